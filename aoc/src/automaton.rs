@@ -0,0 +1,239 @@
+//! Cellular automata, where every cell of a [`Board`] changes each generation
+//! based on its neighbours.
+//!
+//! The neighbourhood is chosen with [`Connectivity`]: [`Connectivity::Four`] is
+//! the von Neumann neighbourhood and [`Connectivity::Eight`] is the Moore
+//! neighbourhood. Neighbours wrap around the edges if the board is
+//! [`Topology::Toroidal`](crate::grid_2d::Topology::Toroidal), and cells on the
+//! edge of a bounded board just have fewer neighbours.
+
+use crate::grid_2d::{Board, Connectivity, Coord};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A board that evolves one generation at a time according to a rule.
+///
+/// The rule is called for every cell with its coordinate, its current value,
+/// and the current values of its neighbours, and returns the cell's value in
+/// the next generation.
+///
+/// # Examples
+/// ```
+/// use aoc::automaton::Automaton;
+/// use aoc::grid_2d::{Board, Connectivity};
+///
+/// // Conway's Game of Life
+/// let board = Board::from_str(
+///     ".....\n\
+///      ..#..\n\
+///      ..#..\n\
+///      ..#..\n\
+///      .....",
+/// );
+///
+/// let mut life = Automaton::new(board.clone(), Connectivity::Eight, |_, cell, neighbours| {
+///     let alive = neighbours.iter().filter(|n| ***n == '#').count();
+///
+///     match (*cell, alive) {
+///         ('#', 2 | 3) | ('.', 3) => '#',
+///         _ => '.',
+///     }
+/// });
+///
+/// life.step();
+/// assert_eq!(life.board(), &board.rotate_cw());
+///
+/// // The blinker repeats every two generations
+/// life.run(999_999);
+/// assert_eq!(life.board(), &board);
+/// assert_eq!(life.generation(), 1_000_000);
+/// ```
+pub struct Automaton<T, F> {
+    current: Board<T>,
+    /// Buffer the next generation is written into, before swapping it with
+    /// the current generation
+    next: Board<T>,
+    connectivity: Connectivity,
+    rule: F,
+    generation: usize,
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone + PartialEq,
+    F: FnMut(Coord, &T, &[&T]) -> T,
+{
+    /// Create an automaton starting from generation 0
+    pub fn new(board: Board<T>, connectivity: Connectivity, rule: F) -> Self {
+        Automaton {
+            next: board.clone(),
+            current: board,
+            connectivity,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// The board in the current generation
+    pub fn board(&self) -> &Board<T> {
+        &self.current
+    }
+
+    /// Consume the automaton, returning the board in the current generation
+    pub fn into_board(self) -> Board<T> {
+        self.current
+    }
+
+    /// Number of generations stepped so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Step forward one generation.
+    ///
+    /// Returns whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut changed = false;
+        let mut neighbours: Vec<&T> = Vec::with_capacity(8);
+
+        for (c, next) in self.next.iter_mut() {
+            let cell = &self.current[c];
+
+            neighbours.clear();
+            neighbours.extend(
+                self.current
+                    .neighbours(&c, self.connectivity)
+                    .map(|(_, item)| item),
+            );
+
+            let new = (self.rule)(c, cell, &neighbours);
+            changed |= new != *cell;
+            *next = new;
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Keep stepping until a generation doesn't change anything.
+    ///
+    /// Returns the generation the board stopped changing at. This never
+    /// returns if the board cycles forever.
+    pub fn run_until_stable(&mut self) -> usize {
+        while self.step() {}
+
+        // The last step didn't change anything
+        self.generation - 1
+    }
+
+    /// Step forward `generations` generations.
+    ///
+    /// Every generation is remembered, so that once the board repeats a
+    /// previous state the rest of the cycles can be skipped.
+    pub fn run(&mut self, generations: usize)
+    where
+        T: Hash + Eq,
+    {
+        let target = self.generation + generations;
+        let mut seen: HashMap<Board<T>, usize> = HashMap::new();
+
+        while self.generation < target {
+            if let Some(&start) = seen.get(&self.current) {
+                // Skip every full cycle that fits before the target
+                let cycle_length = self.generation - start;
+                let remaining = (target - self.generation) % cycle_length;
+
+                for _ in 0..remaining {
+                    self.step();
+                }
+
+                self.generation = target;
+                return;
+            }
+
+            seen.insert(self.current.clone(), self.generation);
+            self.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_2d::Topology;
+
+    fn life_rule(_: Coord, cell: &bool, neighbours: &[&bool]) -> bool {
+        let alive = neighbours.iter().filter(|n| ***n).count();
+
+        matches!((*cell, alive), (true, 2 | 3) | (false, 3))
+    }
+
+    fn parse(input: &str) -> Board<bool> {
+        Board::transform_from_str(input, |c| c == '#')
+    }
+
+    #[test]
+    fn test_glider_wraps() {
+        let board = parse(
+            ".#....\n\
+             ..#...\n\
+             ###...\n\
+             ......\n\
+             ......\n\
+             ......",
+        )
+        .with_topology(Topology::Toroidal);
+
+        let mut life = Automaton::new(board.clone(), Connectivity::Eight, life_rule);
+
+        // A glider moves one cell diagonally every 4 generations, so comes
+        // back to the start after 4 * 6 generations
+        life.run(4);
+        assert_ne!(life.board(), &board);
+        assert_eq!(life.board().find(&true).len(), 5);
+
+        // Skipping lots of cycles ends up in the same place
+        let after_four = life.board().clone();
+        life.run(24 * 1000);
+        assert_eq!(life.board(), &after_four);
+
+        let mut skipped = Automaton::new(board, Connectivity::Eight, life_rule);
+        skipped.run(24 * 1000 + 4);
+        assert_eq!(skipped.board(), &after_four);
+    }
+
+    #[test]
+    fn test_glider_dies_on_bounded_board() {
+        let board = parse(
+            ".#..\n\
+             ..#.\n\
+             ###.\n\
+             ....",
+        );
+
+        let mut life = Automaton::new(board, Connectivity::Eight, life_rule);
+        let stable_at = life.run_until_stable();
+
+        // Gets stuck as a block in the corner
+        assert_eq!(life.board(), &parse("....\n....\n..##\n..##"));
+        assert_eq!(life.generation(), stable_at + 1);
+    }
+
+    #[test]
+    fn test_von_neumann_spread() {
+        let board = parse(".....\n.....\n..#..\n.....\n.....");
+
+        // Anything next to a live cell comes alive
+        let mut spread = Automaton::new(board, Connectivity::Four, |_, cell, neighbours| {
+            *cell || neighbours.iter().any(|n| **n)
+        });
+
+        spread.step();
+        assert_eq!(spread.board().find(&true).len(), 5);
+
+        assert_eq!(spread.run_until_stable(), 4);
+        assert!(spread.board().find(&false).is_empty());
+    }
+}
@@ -1,24 +1,81 @@
-use num::Integer;
+use num::{Integer, PrimInt, Signed};
 use std::collections::HashMap;
-use std::fmt::Display;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+mod ball;
+mod cell;
+mod distance;
+mod lines;
+mod raster;
+mod rect;
+mod regions;
+mod sparse;
+mod transform;
+
+pub use ball::Ball;
+pub use cell::{GridCell, UnknownCell};
+pub use lines::Line;
+pub use raster::{Bresenham, LatticePoints};
+pub use rect::{Coords, Rect};
+pub use regions::{Connectivity, Region, Regions};
+pub use sparse::SparseGrid;
+
+/// Signed integer types that can be used as [`Coord`] components
+pub trait CoordNum: PrimInt + Signed + Integer + Hash + Debug {
+    /// The unsigned integer type of the same width, used for distances
+    type Unsigned: PrimInt + Hash + Debug;
+
+    /// The absolute value, which can't overflow as an unsigned integer
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// The absolute difference between two values
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+}
+
+macro_rules! impl_coord_num {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl CoordNum for $t {
+                type Unsigned = $u;
+
+                fn unsigned_abs(self) -> $u {
+                    <$t>::unsigned_abs(self)
+                }
+
+                fn abs_diff(self, other: Self) -> $u {
+                    <$t>::abs_diff(self, other)
+                }
+            }
+        )*
+    };
+}
 
-/// A (row, col) coordinate pair or vector. Using i32 so that we can subtract
-/// or have negative vectors.
+impl_coord_num!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// A (row, col) coordinate pair or vector. Signed so that we can subtract or
+/// have negative vectors, and i32 by default.
+///
+/// Default type parameters don't drive inference, so a `Coord` built only from
+/// literals may need annotating (`let c: Coord = Coord(1, 2);`) before calling
+/// methods whose return type depends on the component type.
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-pub struct Coord(pub i32, pub i32);
+pub struct Coord<N = i32>(pub N, pub N);
 
-impl Coord {
+impl<N: CoordNum> Coord<N> {
     /// Simplify the coordinate vector by dividing both components by their
     /// greatest common divisor.
     ///
     /// # Examples
     /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
     /// let c = Coord(4, 6);
     /// assert!(c.simplify() == Coord(2, 3));
     /// ```
-    pub fn simplify(&self) -> Coord {
+    pub fn simplify(&self) -> Coord<N> {
         let gcd = self.0.gcd(&self.1);
 
         Coord(self.0 / gcd, self.1 / gcd)
@@ -27,7 +84,7 @@ impl Coord {
     /// Get the neighbours of a coordinate in the cardinal directions.
     ///
     /// Sorted clockwise starting from the north.
-    pub fn cardinal_neighbours(&self) -> [Coord; 4] {
+    pub fn cardinal_neighbours(&self) -> [Coord<N>; 4] {
         [
             self + Dir::North,
             self + Dir::East,
@@ -42,48 +99,118 @@ impl Coord {
     ///
     /// # Examples
     /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
     /// let size = (4usize, 4usize);
     /// let c = Coord(7, -5);
     /// assert!(c.wrap_to_size(size) == Coord(3, 3));
     /// ```
-    pub fn wrap_to_size<T>(self, size: T) -> Coord
+    pub fn wrap_to_size<T>(self, size: T) -> Coord<N>
     where
-        T: Into<Coord>,
+        T: Into<Coord<N>>,
     {
         let size = size.into();
 
-        let row = (self.0 % size.0 + size.0) % size.0;
-        let col = (self.1 % size.1 + size.1) % size.1;
-
-        return (row, col).into();
+        Coord(self.0.mod_floor(&size.0), self.1.mod_floor(&size.1))
     }
 
     /// Compute the manhattan distance between two coordinates
-    pub fn manhattan_distance(&self, other: &Coord) -> u32 {
-        (self.0.abs_diff(other.0) + self.1.abs_diff(other.1)) as u32
+    pub fn manhattan_distance(&self, other: &Coord<N>) -> N::Unsigned {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
     }
 
     /// Compute the L1-norm of the coordinate vector
     ///
     /// The L1-norm is the sum of the absolute values of the components.
-    pub fn l1_norm(&self) -> u32 {
-        (self.0.abs() + self.1.abs()) as u32
+    pub fn l1_norm(&self) -> N::Unsigned {
+        self.0.unsigned_abs() + self.1.unsigned_abs()
     }
 
     /// Compute the L2-norm of the coordinate vector
     pub fn l2_norm(&self) -> f64 {
-        ((self.0 * self.0 + self.1 * self.1) as f64).sqrt()
+        let row = self.0.to_f64().unwrap();
+        let col = self.1.to_f64().unwrap();
+
+        (row * row + col * col).sqrt()
+    }
+
+    /// Compute the dot product of two coordinate vectors
+    pub fn dot(&self, other: &Coord<N>) -> N {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    /// Compute the 2D cross product of two coordinate vectors, which is the
+    /// signed area of the parallelogram they span.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// assert_eq!(Coord(1, 0).cross(&Coord(0, 1)), 1);
+    /// assert_eq!(Coord(0, 1).cross(&Coord(1, 0)), -1);
+    /// assert_eq!(Coord(2, 4).cross(&Coord(1, 2)), 0);
+    /// ```
+    pub fn cross(&self, other: &Coord<N>) -> N {
+        self.0 * other.1 - self.1 * other.0
+    }
+
+    /// Add two coordinates, returning `None` on overflow
+    pub fn checked_add(&self, other: &Coord<N>) -> Option<Coord<N>> {
+        Some(Coord(
+            self.0.checked_add(&other.0)?,
+            self.1.checked_add(&other.1)?,
+        ))
+    }
+
+    /// Subtract two coordinates, returning `None` on overflow
+    pub fn checked_sub(&self, other: &Coord<N>) -> Option<Coord<N>> {
+        Some(Coord(
+            self.0.checked_sub(&other.0)?,
+            self.1.checked_sub(&other.1)?,
+        ))
+    }
+
+    /// Multiply the coordinate by a scalar, returning `None` on overflow
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// assert_eq!(Coord(3, -4).checked_mul(100), Some(Coord(300, -400)));
+    /// assert_eq!(Coord(i32::MAX, 0).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(&self, scalar: N) -> Option<Coord<N>> {
+        Some(Coord(
+            self.0.checked_mul(&scalar)?,
+            self.1.checked_mul(&scalar)?,
+        ))
+    }
+
+    /// Convert the coordinate to a different integer type, returning `None` if
+    /// either component doesn't fit
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// let big: Coord<i64> = Coord(3, -4).cast().unwrap();
+    /// assert_eq!(big * 1_000_000_000, Coord(3_000_000_000, -4_000_000_000));
+    ///
+    /// assert_eq!(Coord(300i32, 0).cast::<i8>(), None);
+    /// ```
+    pub fn cast<M: CoordNum>(self) -> Option<Coord<M>> {
+        Some(Coord(M::from(self.0)?, M::from(self.1)?))
     }
 }
 
-impl From<Coord> for (i32, i32) {
-    fn from(value: Coord) -> Self {
+impl<N> From<Coord<N>> for (N, N) {
+    fn from(value: Coord<N>) -> Self {
         (value.0, value.1)
     }
 }
 
-impl From<(i32, i32)> for Coord {
-    fn from(value: (i32, i32)) -> Self {
+impl<N> From<(N, N)> for Coord<N> {
+    fn from(value: (N, N)) -> Self {
         Coord(value.0, value.1)
     }
 }
@@ -94,54 +221,135 @@ impl From<(usize, usize)> for Coord {
     }
 }
 
-impl Add<Coord> for Coord {
-    type Output = Coord;
+impl<N: CoordNum> Add<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
 
-    fn add(self, rhs: Coord) -> Self::Output {
+    fn add(self, rhs: Coord<N>) -> Self::Output {
         Coord(self.0 + rhs.0, self.1 + rhs.1)
     }
 }
 
-impl Add<&Coord> for &Coord {
-    type Output = Coord;
+impl<N: CoordNum> Add<&Coord<N>> for &Coord<N> {
+    type Output = Coord<N>;
 
-    fn add(self, rhs: &Coord) -> Self::Output {
+    fn add(self, rhs: &Coord<N>) -> Self::Output {
         Coord(self.0 + rhs.0, self.1 + rhs.1)
     }
 }
 
-impl Sub<Coord> for Coord {
-    type Output = Coord;
+impl<N: CoordNum> AddAssign<Coord<N>> for Coord<N> {
+    fn add_assign(&mut self, rhs: Coord<N>) {
+        self.0 = self.0 + rhs.0;
+        self.1 = self.1 + rhs.1;
+    }
+}
+
+impl<N: CoordNum> Sub<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
 
-    fn sub(self, rhs: Coord) -> Self::Output {
+    fn sub(self, rhs: Coord<N>) -> Self::Output {
         Coord(self.0 - rhs.0, self.1 - rhs.1)
     }
 }
 
-impl Sub<&Coord> for &Coord {
-    type Output = Coord;
+impl<N: CoordNum> Sub<&Coord<N>> for &Coord<N> {
+    type Output = Coord<N>;
 
-    fn sub(self, rhs: &Coord) -> Self::Output {
+    fn sub(self, rhs: &Coord<N>) -> Self::Output {
         Coord(self.0 - rhs.0, self.1 - rhs.1)
     }
 }
 
-impl Mul<i32> for Coord {
-    type Output = Coord;
+impl<N: CoordNum> SubAssign<Coord<N>> for Coord<N> {
+    fn sub_assign(&mut self, rhs: Coord<N>) {
+        self.0 = self.0 - rhs.0;
+        self.1 = self.1 - rhs.1;
+    }
+}
 
-    fn mul(self, rhs: i32) -> Self::Output {
+impl<N: CoordNum> Neg for Coord<N> {
+    type Output = Coord<N>;
+
+    fn neg(self) -> Self::Output {
+        Coord(-self.0, -self.1)
+    }
+}
+
+impl<N: CoordNum> Neg for &Coord<N> {
+    type Output = Coord<N>;
+
+    fn neg(self) -> Self::Output {
+        Coord(-self.0, -self.1)
+    }
+}
+
+impl<N: CoordNum> Mul<N> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn mul(self, rhs: N) -> Self::Output {
         Coord(self.0 * rhs, self.1 * rhs)
     }
 }
 
-impl Mul<i32> for &Coord {
-    type Output = Coord;
+impl<N: CoordNum> Mul<N> for &Coord<N> {
+    type Output = Coord<N>;
 
-    fn mul(self, rhs: i32) -> Self::Output {
+    fn mul(self, rhs: N) -> Self::Output {
         Coord(self.0 * rhs, self.1 * rhs)
     }
 }
 
+/// Component-wise multiplication
+impl<N: CoordNum> Mul<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn mul(self, rhs: Coord<N>) -> Self::Output {
+        Coord(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+impl<N: CoordNum> MulAssign<N> for Coord<N> {
+    fn mul_assign(&mut self, rhs: N) {
+        self.0 = self.0 * rhs;
+        self.1 = self.1 * rhs;
+    }
+}
+
+impl<N: CoordNum> Div<N> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn div(self, rhs: N) -> Self::Output {
+        Coord(self.0 / rhs, self.1 / rhs)
+    }
+}
+
+/// Component-wise division
+impl<N: CoordNum> Div<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn div(self, rhs: Coord<N>) -> Self::Output {
+        Coord(self.0 / rhs.0, self.1 / rhs.1)
+    }
+}
+
+impl<N: CoordNum> Rem<N> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn rem(self, rhs: N) -> Self::Output {
+        Coord(self.0 % rhs, self.1 % rhs)
+    }
+}
+
+/// Component-wise remainder. Note that, like `%` on integers, this can be
+/// negative; use [`Coord::wrap_to_size`] to wrap onto a board.
+impl<N: CoordNum> Rem<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn rem(self, rhs: Coord<N>) -> Self::Output {
+        Coord(self.0 % rhs.0, self.1 % rhs.1)
+    }
+}
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
 pub enum Dir {
     North,
@@ -252,11 +460,11 @@ impl Dir {
         }
     }
 
-    pub fn cardinal() -> [Self; 4] {
+    pub const fn cardinal() -> [Self; 4] {
         [Dir::North, Dir::East, Dir::South, Dir::West]
     }
 
-    pub fn all() -> [Self; 8] {
+    pub const fn all() -> [Self; 8] {
         [
             Dir::North,
             Dir::NorthEast,
@@ -275,7 +483,7 @@ impl Dir {
     ///
     /// # Examples
     /// ```
-    /// use grid_2d::Dir;
+    /// use aoc::grid_2d::Dir;
     /// let north = Dir::North;
     /// let east = Dir::East;
     /// let north_east = Dir::NorthEast;
@@ -316,31 +524,40 @@ impl Dir {
     }
 }
 
-impl Add<Dir> for Coord {
-    type Output = Coord;
+impl<N: CoordNum> Add<Dir> for Coord<N> {
+    type Output = Coord<N>;
 
     fn add(self, rhs: Dir) -> Self::Output {
         (&self).add(rhs)
     }
 }
 
-impl Add<Dir> for &Coord {
-    type Output = Coord;
+impl<N: CoordNum> Add<Dir> for &Coord<N> {
+    type Output = Coord<N>;
 
     fn add(self, rhs: Dir) -> Self::Output {
+        let one = N::one();
+
         match rhs {
-            Dir::North => Coord(self.0 - 1, self.1),
-            Dir::NorthEast => Coord(self.0 - 1, self.1 + 1),
-            Dir::East => Coord(self.0, self.1 + 1),
-            Dir::SouthEast => Coord(self.0 + 1, self.1 + 1),
-            Dir::South => Coord(self.0 + 1, self.1),
-            Dir::SouthWest => Coord(self.0 + 1, self.1 - 1),
-            Dir::West => Coord(self.0, self.1 - 1),
-            Dir::NorthWest => Coord(self.0 - 1, self.1 - 1),
+            Dir::North => Coord(self.0 - one, self.1),
+            Dir::NorthEast => Coord(self.0 - one, self.1 + one),
+            Dir::East => Coord(self.0, self.1 + one),
+            Dir::SouthEast => Coord(self.0 + one, self.1 + one),
+            Dir::South => Coord(self.0 + one, self.1),
+            Dir::SouthWest => Coord(self.0 + one, self.1 - one),
+            Dir::West => Coord(self.0, self.1 - one),
+            Dir::NorthWest => Coord(self.0 - one, self.1 - one),
         }
     }
 }
 
+/// Convert a direction to a unit coordinate vector
+impl<N: CoordNum> From<Dir> for Coord<N> {
+    fn from(dir: Dir) -> Self {
+        Coord(N::zero(), N::zero()) + dir
+    }
+}
+
 /// Convert a coordinate vector to a direction
 ///
 /// # Panics
@@ -361,20 +578,161 @@ impl From<Coord> for Dir {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Board<T>
-where
-    T: Clone,
-{
-    pub matrix: Vec<Vec<T>>,
+/// A marker character to pull out of a board while parsing it, with
+/// [`Board::try_parse_with_markers`].
+///
+/// A plain `char` converts into a required marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Marker {
+    /// Has to appear exactly once on the board
+    Required(char),
+    /// Can appear at most once on the board
+    Optional(char),
+}
+
+impl Marker {
+    /// The character that marks the position
+    pub fn char(self) -> char {
+        match self {
+            Marker::Required(c) | Marker::Optional(c) => c,
+        }
+    }
+}
+
+impl From<char> for Marker {
+    fn from(c: char) -> Self {
+        Marker::Required(c)
+    }
+}
+
+/// Error from parsing a [`Board`] with [`Board::try_parse`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBoardError<E> {
+    /// A character couldn't be transformed into a board element
+    InvalidChar {
+        row: usize,
+        col: usize,
+        c: char,
+        /// The error returned by the transform function
+        error: E,
+    },
+    /// A row was a different length than the first row
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A marker character appeared a second time
+    DuplicateMarker {
+        marker: char,
+        row: usize,
+        col: usize,
+    },
+    /// A marker character didn't appear on the board
+    MissingMarker { marker: char },
 }
 
-impl<T> Board<T>
+impl<E> Display for ParseBoardError<E>
 where
-    T: Clone,
+    E: Display,
 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBoardError::InvalidChar { row, col, c, error } => {
+                write!(
+                    f,
+                    "Invalid character {:?} at ({}, {}): {}",
+                    c, row, col, error
+                )
+            }
+            ParseBoardError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} columns, expected {}",
+                row, found, expected
+            ),
+            ParseBoardError::DuplicateMarker { marker, row, col } => {
+                write!(f, "Duplicate marker {:?} at ({}, {})", marker, row, col)
+            }
+            ParseBoardError::MissingMarker { marker } => {
+                write!(f, "Missing marker {:?}", marker)
+            }
+        }
+    }
+}
+
+impl<E> Error for ParseBoardError<E> where E: Debug + Display {}
+
+/// How coordinates off the edge of a [`Board`] are treated
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Default)]
+pub enum Topology {
+    /// Coordinates off the edge of the board aren't on it
+    #[default]
+    Bounded,
+    /// Coordinates off one edge of the board wrap around to the opposite edge
+    Toroidal,
+}
+
+/// A rectangular grid of elements, indexed by (row, col) [`Coord`]s.
+///
+/// Elements are stored contiguously in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    topology: Topology,
+}
+
+impl<T> Board<T> {
+    /// Construct a board from a vector of rows.
+    ///
+    /// # Panics
+    /// Panics if the rows are not all the same length
     pub fn new(matrix: Vec<Vec<T>>) -> Self {
-        Self { matrix }
+        let height = matrix.len();
+        let width = matrix.first().map_or(0, |row| row.len());
+
+        assert!(
+            matrix.iter().all(|row| row.len() == width),
+            "All rows of a board must be the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: matrix.into_iter().flatten().collect(),
+            topology: Topology::Bounded,
+        }
+    }
+
+    /// Construct a board of the given (rows, cols) size from a row-major vector of cells.
+    /// A negative size is treated as zero.
+    ///
+    /// # Panics
+    /// Panics if the number of cells doesn't match the size
+    pub fn from_cells<S>(size: S, cells: Vec<T>) -> Self
+    where
+        S: Into<Coord>,
+    {
+        let size = size.into();
+        let (height, width) = (size.0.max(0) as usize, size.1.max(0) as usize);
+
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Number of cells doesn't match the board size"
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+            topology: Topology::Bounded,
+        }
     }
 
     /// Creates a new board by transforming a string input, mapping each character to a board
@@ -386,9 +744,9 @@ where
     ///
     /// # Examples
     /// ```
-    /// use grid_2d::Board;
+    /// use aoc::grid_2d::{Board, Coord};
     ///
-    /// #[derive(Debug, Clone, Hash)]
+    /// #[derive(Debug, Clone, Hash, PartialEq)]
     /// enum Cell {
     ///     Empty,
     ///     Rock,
@@ -407,9 +765,9 @@ where
     ///     _ => panic!("unexpected character"),
     /// });
     ///
-    /// assert_eq!(board.get(&Coord(0, 0)), Some(Cell::Start));
-    /// assert_eq!(board.get(&Coord(0, 1)), Some(Cell::Rock));
-    /// assert_eq!(board.get(&Coord(2, 2)), Some(Cell::Rock));
+    /// assert_eq!(board.get(&Coord(0, 0)), Some(&Cell::Sand));
+    /// assert_eq!(board.get(&Coord(0, 1)), Some(&Cell::Rock));
+    /// assert_eq!(board[Coord(2, 2)], Cell::Rock);
     /// ```
     pub fn transform_from_str<F>(input: &str, transform: F) -> Self
     where
@@ -417,49 +775,384 @@ where
     {
         let matrix: Vec<Vec<T>> = input
             .lines()
-            .map(|line| line.chars().map(|c| transform(c)).collect())
+            .map(|line| line.chars().map(&transform).collect())
             .collect();
 
         Self::new(matrix)
     }
 
+    /// Creates a new board by transforming a string input like
+    /// [`Board::transform_from_str`], but validates the input instead of panicking.
+    ///
+    /// Every row must be the same length, and `transform` can reject a character
+    /// by returning an error. The first problem found is returned along with
+    /// where on the board it was.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, ParseBoardError};
+    ///
+    /// let parse = |c| match c {
+    ///     '#' => Ok(true),
+    ///     '.' => Ok(false),
+    ///     _ => Err("unrecognized character"),
+    /// };
+    ///
+    /// let board = Board::try_parse("#.\n.#", parse).unwrap();
+    /// assert_eq!(board.size(), (2, 2));
+    ///
+    /// let err = Board::try_parse("#.\n.?", parse).unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     ParseBoardError::InvalidChar {
+    ///         row: 1,
+    ///         col: 1,
+    ///         c: '?',
+    ///         error: "unrecognized character",
+    ///     }
+    /// );
+    ///
+    /// let err = Board::try_parse("#.\n.", parse).unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     ParseBoardError::RaggedRow {
+    ///         row: 1,
+    ///         expected: 2,
+    ///         found: 1,
+    ///     }
+    /// );
+    /// ```
+    pub fn try_parse<F, E>(input: &str, transform: F) -> Result<Self, ParseBoardError<E>>
+    where
+        F: Fn(char) -> Result<T, E>,
+    {
+        Self::parse_cells(input, |row, col, c| {
+            transform(c).map_err(|error| ParseBoardError::InvalidChar { row, col, c, error })
+        })
+    }
+
+    /// Parse a board like [`Board::try_parse`], pulling out the positions of
+    /// marker characters (such as the start and end points) as it goes.
+    ///
+    /// Markers can be plain `char`s, which must appear exactly once on the
+    /// board, or [`Marker`]s to allow some of them to be missing. No marker can
+    /// appear more than once. Their positions are filled with `fill` instead of
+    /// being passed to `transform`.
+    ///
+    /// Returns the board, and a map from each marker that appeared to its
+    /// position.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Marker, ParseBoardError};
+    ///
+    /// let parse = |c| match c {
+    ///     '#' => Ok(true),
+    ///     '.' => Ok(false),
+    ///     _ => Err("unrecognized character"),
+    /// };
+    ///
+    /// let (board, markers) = Board::try_parse_with_markers(
+    ///     "S.#\n\
+    ///      #.E",
+    ///     &['S', 'E'],
+    ///     false,
+    ///     parse,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(markers[&'S'], Coord(0, 0));
+    /// assert_eq!(markers[&'E'], Coord(1, 2));
+    /// assert!(!board[Coord(1, 2)]);
+    ///
+    /// let err = Board::try_parse_with_markers("S.S", &['S', 'E'], false, parse).unwrap_err();
+    /// assert_eq!(
+    ///     err,
+    ///     ParseBoardError::DuplicateMarker {
+    ///         marker: 'S',
+    ///         row: 0,
+    ///         col: 2,
+    ///     }
+    /// );
+    ///
+    /// // The exit doesn't have to be there
+    /// let markers = [Marker::Required('S'), Marker::Optional('E')];
+    /// let (_, positions) = Board::try_parse_with_markers("S.#", &markers, false, parse).unwrap();
+    /// assert_eq!(positions.get(&'E'), None);
+    /// ```
+    pub fn try_parse_with_markers<M, F, E>(
+        input: &str,
+        markers: &[M],
+        fill: T,
+        transform: F,
+    ) -> Result<(Self, HashMap<char, Coord>), ParseBoardError<E>>
+    where
+        M: Into<Marker> + Copy,
+        F: Fn(char) -> Result<T, E>,
+        T: Clone,
+    {
+        let markers: Vec<Marker> = markers.iter().map(|&m| m.into()).collect();
+        let mut positions: HashMap<char, Coord> = HashMap::new();
+
+        let board = Self::parse_cells(input, |row, col, c| {
+            if !markers.iter().any(|m| m.char() == c) {
+                return transform(c).map_err(|error| ParseBoardError::InvalidChar {
+                    row,
+                    col,
+                    c,
+                    error,
+                });
+            }
+
+            if positions.insert(c, (row, col).into()).is_some() {
+                return Err(ParseBoardError::DuplicateMarker {
+                    marker: c,
+                    row,
+                    col,
+                });
+            }
+
+            Ok(fill.clone())
+        })?;
+
+        if let Some(marker) = markers.iter().find_map(|m| match m {
+            Marker::Required(c) if !positions.contains_key(c) => Some(*c),
+            _ => None,
+        }) {
+            return Err(ParseBoardError::MissingMarker { marker });
+        }
+
+        Ok((board, positions))
+    }
+
+    /// Build a board from a string, transforming each character given its
+    /// (row, col) position. Checks that all rows are the same length.
+    fn parse_cells<F, E>(input: &str, mut transform: F) -> Result<Self, ParseBoardError<E>>
+    where
+        F: FnMut(usize, usize, char) -> Result<T, ParseBoardError<E>>,
+    {
+        let mut cells: Vec<T> = Vec::new();
+        // Set by the first row, which every other row has to match
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+
+            for (col, c) in line.chars().enumerate() {
+                cells.push(transform(row, col, c)?);
+                row_width += 1;
+            }
+
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(ParseBoardError::RaggedRow {
+                    row,
+                    expected,
+                    found: row_width,
+                });
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+            topology: Topology::Bounded,
+        })
+    }
+
     /// Construct a board with the given size, and fill all elements with the
-    /// given item
+    /// given item. A negative size gives an empty board.
     pub fn from_size<S>(size: S, item: T) -> Self
     where
         S: Into<Coord>,
+        T: Clone,
     {
         let size = size.into();
+        let (height, width) = (size.0.max(0) as usize, size.1.max(0) as usize);
 
-        let matrix: Vec<Vec<T>> = (0..size.0)
-            .map(|_x| (0..size.1).map(|_y| item.clone()).collect())
+        Self {
+            width,
+            height,
+            cells: vec![item; width * height],
+            topology: Topology::Bounded,
+        }
+    }
+
+    /// Construct a board with the given size, where each element is the result
+    /// of calling `f` with its coordinate
+    pub fn from_fn<S, F>(size: S, mut f: F) -> Self
+    where
+        S: Into<Coord>,
+        F: FnMut(Coord) -> T,
+    {
+        let size = size.into();
+        let (height, width) = (size.0.max(0) as usize, size.1.max(0) as usize);
+
+        let cells = (0..height * width)
+            .map(|i| f((i / width, i % width).into()))
             .collect();
 
-        Self::new(matrix)
+        Self {
+            width,
+            height,
+            cells,
+            topology: Topology::Bounded,
+        }
     }
 
+    /// Get the (rows, cols) size of the board
     pub fn size(&self) -> (usize, usize) {
-        (self.matrix.len(), self.matrix[0].len())
+        (self.height, self.width)
+    }
+
+    /// Number of columns on the board
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows on the board
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Set how coordinates off the edge of the board are treated
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Topology};
+    ///
+    /// let board = Board::from_str("ab\ncd").with_topology(Topology::Toroidal);
+    ///
+    /// assert_eq!(board.get(&Coord(-1, 0)), Some(&'c'));
+    /// assert_eq!(board[Coord(5, 7)], 'd');
+    /// ```
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
     }
 
-    pub fn get(&self, c: &Coord) -> Option<T> {
-        let (rows, cols) = self.size();
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    /// Whether the coordinate lies within the edges of the board, regardless
+    /// of the board's topology
+    pub fn contains(&self, c: &Coord) -> bool {
+        c.0 >= 0 && (c.0 as usize) < self.height && c.1 >= 0 && (c.1 as usize) < self.width
+    }
 
-        if c.0 < 0 || c.0 as usize >= rows || c.1 < 0 || c.1 as usize >= cols {
-            return None;
+    /// Get the position on the board that a coordinate refers to.
+    ///
+    /// For a bounded board this is the coordinate itself if it's on the board,
+    /// and for a toroidal board the coordinate is wrapped around the edges.
+    /// Always `None` for an empty board.
+    pub fn normalize(&self, c: &Coord) -> Option<Coord> {
+        match self.topology {
+            _ if self.cells.is_empty() => None,
+            Topology::Bounded => self.contains(c).then_some(*c),
+            Topology::Toroidal => Some(c.wrap_to_size((self.height, self.width))),
         }
+    }
+
+    /// Index into `cells` for a coordinate, if it's on the board
+    fn index_of(&self, c: &Coord) -> Option<usize> {
+        self.normalize(c)
+            .map(|c| c.0 as usize * self.width + c.1 as usize)
+    }
+
+    /// Get the neighbours of a coordinate that are on the board, along with
+    /// their elements. On a toroidal board, neighbours wrap around the edges.
+    ///
+    /// Each neighbouring cell is only yielded once, and a cell is never its
+    /// own neighbour. On a toroidal board less than 3 cells across, wrapping
+    /// in opposite directions reaches the same cells, so there are fewer
+    /// neighbours than usual.
+    pub fn neighbours(
+        &self,
+        c: &Coord,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let origin = self.normalize(c);
+        let mut seen: Vec<Coord> = Vec::with_capacity(8);
+
+        connectivity
+            .neighbours(c)
+            .filter_map(|n| self.normalize(&n))
+            .filter(move |n| {
+                if Some(*n) == origin || seen.contains(n) {
+                    return false;
+                }
+                seen.push(*n);
+                true
+            })
+            .map(|n| (n, &self[n]))
+    }
+
+    /// Coordinate of an index into `cells`
+    fn coord_of(&self, index: usize) -> Coord {
+        (index / self.width, index % self.width).into()
+    }
 
-        Some(self.matrix[c.0 as usize][c.1 as usize].clone())
+    pub fn get(&self, c: &Coord) -> Option<&T> {
+        self.index_of(c).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, c: &Coord) -> Option<&mut T> {
+        self.index_of(c).map(|i| &mut self.cells[i])
     }
 
     /// Get the value at a coordinate without checking for bounds
     ///
     /// # Panics
     /// Panics if the coordinate is outside of the board
-    pub fn get_unchecked(&self, c: &Coord) -> T {
+    pub fn get_unchecked(&self, c: &Coord) -> &T {
         self.get(c).unwrap()
     }
 
+    /// Set the value at a coordinate
+    ///
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    pub fn set(&mut self, c: &Coord, val: T) {
+        self[*c] = val;
+    }
+
+    /// Iterate over the rows of the board, as slices. Use [`Board::rows`] to get
+    /// the coordinates as well.
+    pub fn row_slices(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a size of 0, which would only happen on a board
+        // with no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterate over every element of the board along with its coordinate,
+    /// in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (self.coord_of(i), item))
+    }
+
+    /// Iterate mutably over every element of the board along with its coordinate,
+    /// in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, item)| ((i / width, i % width).into(), item))
+    }
+
     /// Find the position of all occurrences of `elem` on the board.
     ///
     /// Returns a vector of coordinates.
@@ -467,26 +1160,12 @@ where
     where
         T: Eq,
     {
-        self.matrix
-            .iter()
-            .enumerate()
-            .map(|(i, row)| {
-                row.iter().enumerate().filter_map(move |(j, e)| {
-                    if e == elem {
-                        Some((i, j).into())
-                    } else {
-                        None
-                    }
-                })
-            })
-            .flatten()
+        self.iter()
+            .filter(|(_, e)| *e == elem)
+            .map(|(c, _)| c)
             .collect()
     }
 
-    pub fn set(&mut self, c: &Coord, val: T) {
-        self.matrix[c.0 as usize][c.1 as usize] = val;
-    }
-
     /// Returns a HashMap containing positions of elements that match the given filter.
     /// Elements are grouped by type, with their positions collected into a Vec<Coord>.
     ///
@@ -494,7 +1173,7 @@ where
     ///
     /// # Examples
     /// ```
-    /// use grid_2d::{Board, Coord};
+    /// use aoc::grid_2d::{Board, Coord};
     ///
     /// // Using with a char board - collecting all non-empty spaces
     /// let board = Board::from_str(
@@ -537,17 +1216,15 @@ where
     {
         let mut result = HashMap::new();
 
-        for (i, row) in self.matrix.iter().enumerate() {
-            for (j, item) in row.iter().enumerate() {
-                if !filter(item) {
-                    continue;
-                }
-
-                result
-                    .entry(item.clone())
-                    .or_insert_with(Vec::new)
-                    .push((i, j).into());
+        for (coord, item) in self.iter() {
+            if !filter(item) {
+                continue;
             }
+
+            result
+                .entry(item.clone())
+                .or_insert_with(Vec::new)
+                .push(coord);
         }
 
         result
@@ -555,9 +1232,7 @@ where
 
     /// Construct a vector of all coordinate positions on the board
     pub fn positions(&self) -> Vec<Coord> {
-        (0..self.matrix.len())
-            .flat_map(|row| (0..self.matrix[row].len()).map(move |col| (row, col).into()))
-            .collect()
+        (0..self.cells.len()).map(|i| self.coord_of(i)).collect()
     }
 
     /// Print the board to the terminal
@@ -565,7 +1240,7 @@ where
     where
         T: Display,
     {
-        for row in self.matrix.iter() {
+        for row in self.row_slices() {
             for item in row.iter() {
                 print!("{}", item);
             }
@@ -578,50 +1253,342 @@ where
     where
         T: Display,
     {
-        let (rows, cols) = self.size();
-        let row_space = (rows - 1).to_string().len();
-        let col_space = (cols - 1).to_string().len();
+        print!("{}", grid_with_axes(Coord(0, 0), self.size(), |c| &self[c]));
+    }
+}
 
-        let row_labels: Vec<String> = (0..rows)
-            // There's intentionally an extra space here
-            .map(|i| format!("{:0>width$} ", i, width = row_space))
-            .collect();
-        let col_labels: Vec<String> = (0..cols)
-            .map(|i| format!("{:0>width$}", i, width = col_space))
-            .collect();
+/// Format a grid of the given (rows, cols) size with axes numbers, where the top
+/// left element is at `origin`. `element` gets what to show at each coordinate.
+pub(crate) fn grid_with_axes<F, D>(origin: Coord, size: (usize, usize), element: F) -> String
+where
+    F: Fn(Coord) -> D,
+    D: Display,
+{
+    let mut out = String::new();
+
+    let (rows, cols) = size;
+    let row_numbers = origin.0..origin.0 + rows as i32;
+    let col_numbers = origin.1..origin.1 + cols as i32;
+
+    let label_space =
+        |numbers: std::ops::Range<i32>| numbers.map(|i| i.to_string().len()).max().unwrap_or(1);
+    let row_space = label_space(row_numbers.clone());
+    let col_space = label_space(col_numbers.clone());
+
+    let row_labels: Vec<String> = row_numbers
+        .clone()
+        // There's intentionally an extra space here
+        .map(|i| format!("{:0width$} ", i, width = row_space))
+        .collect();
+    let col_labels: Vec<String> = col_numbers
+        .clone()
+        .map(|i| format!("{:0width$}", i, width = col_space))
+        .collect();
+
+    // Print the labels for the columns along the top first
+    for i in 0..col_space {
+        // Buffer room for row labels, including an extra space
+        for _ in 0..=row_space {
+            out.push(' ');
+        }
 
-        // Print the labels for the columns along the top first
-        for i in 0..col_space {
-            // Buffer room for row labels, including an extra space
-            for _ in 0..=row_space {
-                print!(" ")
-            }
+        for label in col_labels.iter() {
+            out.push_str(&label[i..=i]);
+        }
+        out.push('\n');
+    }
 
-            for j in 0..cols {
-                let e = &col_labels[j][i..=i];
-                print!("{}", e);
-            }
-            println!();
+    for (label, row) in row_labels.iter().zip(row_numbers) {
+        // Print the row labels
+        out.push_str(label);
+
+        // Print the actual grid items
+        for col in col_numbers.clone() {
+            out.push_str(&element(Coord(row, col)).to_string());
         }
 
-        for (i, row) in self.matrix.iter().enumerate() {
-            // Print the row labels
-            print!("{}", row_labels[i]);
+        out.push('\n');
+    }
 
-            // Print the actual grid items
-            for item in row.iter() {
-                print!("{}", item);
-            }
+    out
+}
 
-            println!();
-        }
+impl<T> Index<Coord> for Board<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    fn index(&self, c: Coord) -> &Self::Output {
+        let i = self
+            .index_of(&c)
+            .unwrap_or_else(|| panic!("Coordinate {:?} is outside of the board", c));
+
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Coord> for Board<T> {
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    fn index_mut(&mut self, c: Coord) -> &mut Self::Output {
+        let i = self
+            .index_of(&c)
+            .unwrap_or_else(|| panic!("Coordinate {:?} is outside of the board", c));
+
+        &mut self.cells[i]
     }
 }
 
 impl Board<char> {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(input: &str) -> Self {
-        let matrix: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        Self::transform_from_str(input, |c| c)
+    }
+}
 
-        Self::new(matrix)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coord_arithmetic() {
+        let mut c: Coord = Coord(3, -4);
+
+        assert_eq!(-c, Coord(-3, 4));
+        assert_eq!(c * Coord(2, 3), Coord(6, -12));
+        assert_eq!(c / 2, Coord(1, -2));
+        assert_eq!(c % 2, Coord(1, 0));
+        assert_eq!(c.dot(&Coord(2, 1)), 2);
+        assert_eq!(c.l1_norm(), 7);
+        assert_eq!(c.l2_norm(), 5.0);
+
+        c += Coord(1, 1);
+        c -= Coord(0, 2);
+        c *= 2;
+        assert_eq!(c, Coord(8, -10));
+
+        assert_eq!(Coord(i32::MAX, 0).checked_add(&Coord(1, 0)), None);
+        assert_eq!(Coord(0, i32::MIN).checked_sub(&Coord(0, 1)), None);
+    }
+
+    #[test]
+    fn test_wide_coord() {
+        // A robot moving for a long time on a large board, which would
+        // overflow with i32
+        let position: Coord<i64> = Coord(5, 7);
+        let velocity: Coord<i64> = Coord(-3, 100_000);
+        let end = (position + velocity * 1_000_000).wrap_to_size(Coord(1_000_003, 999_983));
+
+        assert_eq!(
+            end,
+            Coord(1_000_003 - 2_999_995 % 1_000_003, 100_000_000_007 % 999_983)
+        );
+        assert_eq!(Coord(0i64, 0).manhattan_distance(&velocity), 100_003u64);
+        assert_eq!(end.cast::<i32>(), Some(Coord(end.0 as i32, end.1 as i32)));
+        assert_eq!(Coord::<i64>::from(Dir::SouthWest), Coord(1, -1));
+    }
+
+    #[test]
+    fn test_board_indexing() {
+        let mut board = Board::from_str("abc\ndef");
+
+        assert_eq!(board.size(), (2, 3));
+        assert_eq!(board.get(&Coord(1, 2)), Some(&'f'));
+        assert_eq!(board.get(&Coord(2, 0)), None);
+        assert_eq!(board.get(&Coord(0, -1)), None);
+        assert_eq!(board[Coord(0, 1)], 'b');
+
+        board[Coord(0, 1)] = 'x';
+        *board.get_mut(&Coord(1, 0)).unwrap() = 'y';
+        board.set(&Coord(1, 1), 'z');
+
+        assert_eq!(board, Board::from_str("axc\nyzf"));
+        assert_eq!(board.find(&'z'), vec![Coord(1, 1)]);
+    }
+
+    #[test]
+    fn test_empty_board() {
+        let board: Board<char> = Board::from_str("");
+
+        assert_eq!(board.size(), (0, 0));
+        assert_eq!(board.get(&Coord(0, 0)), None);
+        assert!(board.positions().is_empty());
+
+        // Negative sizes are empty rather than overflowing
+        assert_eq!(Board::from_size(Coord(-1, 3), 0u8).size(), (0, 3));
+        assert_eq!(
+            Board::<u8>::from_cells(Coord(2, -2), Vec::new()).size(),
+            (2, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_board() {
+        Board::from_str("abc\nde");
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let parse = |c: char| c.to_digit(10).ok_or(());
+
+        let board = Board::try_parse("123\n456\n", parse).unwrap();
+        assert_eq!(board.size(), (2, 3));
+        assert_eq!(board[Coord(1, 2)], 6);
+
+        let empty = Board::try_parse("", parse).unwrap();
+        assert_eq!(empty.size(), (0, 0));
+
+        assert_eq!(
+            Board::try_parse("123\n4x6", parse),
+            Err(ParseBoardError::InvalidChar {
+                row: 1,
+                col: 1,
+                c: 'x',
+                error: ()
+            })
+        );
+        assert_eq!(
+            Board::try_parse("123\n4567", parse),
+            Err(ParseBoardError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let err: ParseBoardError<&str> = ParseBoardError::InvalidChar {
+            row: 3,
+            col: 7,
+            c: '?',
+            error: "unknown cell",
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid character '?' at (3, 7): unknown cell"
+        );
+    }
+
+    #[test]
+    fn test_try_parse_with_markers() {
+        let parse = |c: char| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(()),
+        };
+
+        let (board, markers) =
+            Board::try_parse_with_markers("#S.\n.E#", &['S', 'E'], '.', parse).unwrap();
+
+        assert_eq!(board, Board::from_str("#..\n..#"));
+        assert_eq!(
+            markers,
+            HashMap::from([('S', Coord(0, 1)), ('E', Coord(1, 1))])
+        );
+
+        assert_eq!(
+            Board::try_parse_with_markers("#S.\n..#", &['S', 'E'], '.', parse),
+            Err(ParseBoardError::MissingMarker { marker: 'E' })
+        );
+        assert_eq!(
+            Board::try_parse_with_markers("#S.\nES#", &['S', 'E'], '.', parse),
+            Err(ParseBoardError::DuplicateMarker {
+                marker: 'S',
+                row: 1,
+                col: 1
+            })
+        );
+        // Optional markers can be missing, but not duplicated
+        let optional = [Marker::Required('S'), Marker::Optional('E')];
+        let (_, markers) =
+            Board::try_parse_with_markers("#S.\n..#", &optional, '.', parse).unwrap();
+        assert_eq!(markers, HashMap::from([('S', Coord(0, 1))]));
+        assert_eq!(
+            Board::try_parse_with_markers("ES.\n.E#", &optional, '.', parse),
+            Err(ParseBoardError::DuplicateMarker {
+                marker: 'E',
+                row: 1,
+                col: 1
+            })
+        );
+
+        // Anything else still goes through the transform
+        assert_eq!(
+            Board::try_parse_with_markers("#S?\n.E#", &['S', 'E'], '.', parse),
+            Err(ParseBoardError::InvalidChar {
+                row: 0,
+                col: 2,
+                c: '?',
+                error: ()
+            })
+        );
+    }
+
+    #[test]
+    fn test_toroidal_board() {
+        let mut board = Board::from_str("abc\ndef").with_topology(Topology::Toroidal);
+
+        assert_eq!(board.get(&Coord(2, 3)), Some(&'a'));
+        assert_eq!(board.get(&Coord(-1, -1)), Some(&'f'));
+        assert_eq!(board.normalize(&Coord(-3, 7)), Some(Coord(1, 1)));
+        assert!(!board.contains(&Coord(-3, 7)));
+
+        board.set(&Coord(4, -3), 'x');
+        assert_eq!(board[Coord(0, 0)], 'x');
+
+        let neighbours: Vec<(Coord, &char)> =
+            board.neighbours(&Coord(0, 0), Connectivity::Four).collect();
+        // Only 2 rows, so north and south are the same cell
+        assert_eq!(
+            neighbours,
+            vec![
+                (Coord(1, 0), &'d'),
+                (Coord(0, 1), &'b'),
+                (Coord(0, 2), &'c')
+            ]
+        );
+
+        // Every other cell is next to (0, 0), once
+        let neighbours: Vec<Coord> = board
+            .neighbours(&Coord(0, 0), Connectivity::Eight)
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(neighbours.len(), 5);
+        assert!(!neighbours.contains(&Coord(0, 0)));
+
+        // Wrapping on a single column only reaches the cell itself
+        let column = Board::from_str("a\nb\nc").with_topology(Topology::Toroidal);
+        assert_eq!(
+            column
+                .neighbours(&Coord(0, 0), Connectivity::Four)
+                .collect::<Vec<_>>(),
+            vec![(Coord(2, 0), &'c'), (Coord(1, 0), &'b')]
+        );
+
+        // Bounded boards don't wrap
+        let board = board.with_topology(Topology::Bounded);
+        assert_eq!(board.get(&Coord(2, 3)), None);
+        assert_eq!(
+            board.neighbours(&Coord(0, 0), Connectivity::Four).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_toroidal_regions() {
+        let board = Board::from_str("#..#\n....\n#..#");
+
+        let bounded = board.regions_where(Connectivity::Four, |c| *c == '#');
+        assert_eq!(bounded.len(), 4);
+
+        // All four corners join up around the edges
+        let toroidal = board
+            .with_topology(Topology::Toroidal)
+            .regions_where(Connectivity::Four, |c| *c == '#');
+        assert_eq!(toroidal.len(), 1);
+        assert_eq!(toroidal.regions[0].area(), 4);
     }
 }
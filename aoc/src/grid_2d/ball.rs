@@ -0,0 +1,242 @@
+use super::{Board, Coord, Rect};
+
+/// How distance is measured for a [`Ball`]
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+enum Metric {
+    /// Number of cardinal steps, giving a diamond shape
+    Manhattan,
+    /// Number of cardinal or diagonal steps, giving a square shape
+    Chebyshev,
+}
+
+/// Iterator over every cell within some distance of a center cell, yielding
+/// `(Coord, distance)` pairs row by row.
+///
+/// Created by [`Coord::within_manhattan`] and [`Coord::within_chebyshev`].
+#[derive(Debug, Clone)]
+pub struct Ball {
+    center: Coord,
+    radius: i32,
+    metric: Metric,
+    clip: Option<Rect>,
+    row: i32,
+    last_row: i32,
+    col: i32,
+    last_col: i32,
+}
+
+impl Ball {
+    fn new(center: Coord, radius: u32, metric: Metric, clip: Option<Rect>) -> Self {
+        let radius = radius as i32;
+        let mut ball = Ball {
+            center,
+            radius,
+            metric,
+            clip,
+            row: center.0 - radius,
+            last_row: center.0 + radius,
+            col: 0,
+            last_col: -1,
+        };
+
+        if let Some(clip) = clip {
+            ball.row = ball.row.max(clip.min().0);
+            ball.last_row = ball.last_row.min(clip.max().0);
+        }
+
+        ball.start_row();
+        ball
+    }
+
+    /// Only yield cells that are inside a rectangle
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Coord, Rect};
+    ///
+    /// let corner = Rect::new(Coord(0, 0), Coord(9, 9));
+    /// let cells: Vec<_> = Coord(0, 0).within_manhattan(1).clip(corner).collect();
+    ///
+    /// assert_eq!(cells, vec![(Coord(0, 0), 0), (Coord(0, 1), 1), (Coord(1, 0), 1)]);
+    /// ```
+    pub fn clip(self, bounds: Rect) -> Self {
+        let bounds = match self.clip {
+            Some(clip) => clip.intersection(&bounds),
+            None => Some(bounds),
+        };
+
+        match bounds {
+            Some(bounds) => Ball::new(self.center, self.radius as u32, self.metric, Some(bounds)),
+            None => self.empty(),
+        }
+    }
+
+    /// Stop the iterator from yielding anything else
+    fn empty(self) -> Self {
+        Ball {
+            last_row: self.row - 1,
+            ..self
+        }
+    }
+
+    /// Set up the range of columns to visit in the current row
+    fn start_row(&mut self) {
+        let half_width = match self.metric {
+            Metric::Manhattan => self.radius - (self.row - self.center.0).abs(),
+            Metric::Chebyshev => self.radius,
+        };
+
+        self.col = self.center.1 - half_width;
+        self.last_col = self.center.1 + half_width;
+
+        if let Some(clip) = self.clip {
+            self.col = self.col.max(clip.min().1);
+            self.last_col = self.last_col.min(clip.max().1);
+        }
+    }
+}
+
+impl Iterator for Ball {
+    type Item = (Coord, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.row <= self.last_row {
+            if self.col <= self.last_col {
+                let c = Coord(self.row, self.col);
+                self.col += 1;
+
+                let distance = match self.metric {
+                    Metric::Manhattan => c.manhattan_distance(&self.center),
+                    Metric::Chebyshev => {
+                        self.center.0.abs_diff(c.0).max(self.center.1.abs_diff(c.1))
+                    }
+                };
+
+                return Some((c, distance));
+            }
+
+            self.row += 1;
+            self.start_row();
+        }
+
+        None
+    }
+}
+
+impl Coord {
+    /// Iterate over every cell within a manhattan distance of `radius`, along
+    /// with its distance. This is a diamond shape, including the center cell.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// let center = Coord(5, -3);
+    /// let cells: Vec<_> = center.within_manhattan(20).collect();
+    ///
+    /// // 2r² + 2r + 1 cells in a diamond
+    /// assert_eq!(cells.len(), 841);
+    /// assert!(cells.iter().all(|(c, d)| c.manhattan_distance(&center) == *d && *d <= 20));
+    /// ```
+    pub fn within_manhattan(&self, radius: u32) -> Ball {
+        Ball::new(*self, radius, Metric::Manhattan, None)
+    }
+
+    /// Iterate over every cell within `radius` cardinal or diagonal steps, along
+    /// with the number of steps. This is a square shape, including the center
+    /// cell.
+    pub fn within_chebyshev(&self, radius: u32) -> Ball {
+        Ball::new(*self, radius, Metric::Chebyshev, None)
+    }
+}
+
+impl<T> Board<T> {
+    /// Iterate over every cell on the board within a manhattan distance of
+    /// `radius` from the center, along with its distance.
+    ///
+    /// Cells off the edge of the board are skipped, even on a toroidal board.
+    pub fn within_manhattan(&self, center: &Coord, radius: u32) -> Ball {
+        self.clip_ball(center.within_manhattan(radius))
+    }
+
+    /// Iterate over every cell on the board within `radius` cardinal or diagonal
+    /// steps of the center, along with the number of steps.
+    ///
+    /// Cells off the edge of the board are skipped, even on a toroidal board.
+    pub fn within_chebyshev(&self, center: &Coord, radius: u32) -> Ball {
+        self.clip_ball(center.within_chebyshev(radius))
+    }
+
+    fn clip_ball(&self, ball: Ball) -> Ball {
+        match self.bounds() {
+            Some(bounds) => ball.clip(bounds),
+            None => ball.empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_manhattan_ball() {
+        let center = Coord(2, 2);
+
+        let expected: HashSet<(Coord, u32)> = Rect::around(center, 3)
+            .iter()
+            .map(|c| (c, c.manhattan_distance(&center)))
+            .filter(|(_, d)| *d <= 3)
+            .collect();
+        let cells: Vec<(Coord, u32)> = center.within_manhattan(3).collect();
+
+        assert_eq!(cells.len(), expected.len());
+        assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+        assert_eq!(
+            center.within_manhattan(0).collect::<Vec<_>>(),
+            vec![(center, 0)]
+        );
+    }
+
+    #[test]
+    fn test_chebyshev_ball() {
+        let center = Coord(-4, 1);
+        let cells: Vec<(Coord, u32)> = center.within_chebyshev(2).collect();
+
+        assert_eq!(cells.len(), 25);
+        assert_eq!(cells[0], (Coord(-6, -1), 2));
+        assert!(cells.contains(&(Coord(-3, 2), 1)));
+        assert_eq!(
+            cells.iter().map(|(c, _)| *c).collect::<Vec<_>>(),
+            Rect::around(center, 2).iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_clipped_to_board() {
+        let board = Board::from_str("abc\ndef\nghi");
+
+        let cells: Vec<Coord> = board
+            .within_manhattan(&Coord(0, 0), 2)
+            .map(|(c, _)| c)
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                Coord(0, 0),
+                Coord(0, 1),
+                Coord(0, 2),
+                Coord(1, 0),
+                Coord(1, 1),
+                Coord(2, 0)
+            ]
+        );
+
+        assert_eq!(board.within_chebyshev(&Coord(1, 1), 5).count(), 9);
+        assert_eq!(board.within_chebyshev(&Coord(10, 10), 2).count(), 0);
+
+        let empty: Board<char> = Board::from_size((0, 0), '.');
+        assert_eq!(empty.within_manhattan(&Coord(0, 0), 3).count(), 0);
+    }
+}
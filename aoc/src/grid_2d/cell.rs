@@ -0,0 +1,198 @@
+use super::{Board, Coord, Marker, ParseBoardError};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// A type of cell that can be parsed from and printed as a single character.
+///
+/// Usually implemented with the [`grid_cell!`](crate::grid_cell) macro, which
+/// also implements [`Display`] so that [`Board::print`] works.
+pub trait GridCell: Sized {
+    /// Convert a character from the puzzle input into a cell, or `None` if the
+    /// character isn't recognized
+    fn from_char(c: char) -> Option<Self>;
+
+    /// Convert the cell back into its character
+    fn to_char(&self) -> char;
+}
+
+impl GridCell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Error from parsing a character that isn't any kind of [`GridCell`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCell;
+
+impl Display for UnknownCell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not a known cell")
+    }
+}
+
+impl std::error::Error for UnknownCell {}
+
+/// Define an enum of board cells from a table of characters, implementing
+/// [`GridCell`](crate::grid_2d::GridCell) and [`Display`](std::fmt::Display)
+/// to convert in both directions.
+///
+/// # Examples
+/// ```
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::grid_cell;
+///
+/// grid_cell! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub enum Cell {
+///         '.' => Empty,
+///         '#' => Wall,
+///         /// Robots can't walk on lava
+///         '~' => Lava,
+///     }
+/// }
+///
+/// let board: Board<Cell> = Board::parse(".#\n~.").unwrap();
+///
+/// assert_eq!(board[Coord(1, 0)], Cell::Lava);
+/// assert_eq!(board.to_string(), ".#\n~.\n");
+/// assert_eq!(Cell::Wall.to_string(), "#");
+/// ```
+#[macro_export]
+macro_rules! grid_cell {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $c:literal => $variant:ident
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $crate::grid_2d::GridCell for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($c => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $($name::$variant => $c,)+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", $crate::grid_2d::GridCell::to_char(self))
+            }
+        }
+    };
+}
+
+impl<T> Board<T>
+where
+    T: GridCell,
+{
+    /// Parse a board of cells, one character per cell
+    ///
+    /// # Errors
+    /// Returns an error if a character isn't a known cell, or the rows are
+    /// different lengths
+    pub fn parse(input: &str) -> Result<Self, ParseBoardError<UnknownCell>> {
+        Self::try_parse(input, |c| T::from_char(c).ok_or(UnknownCell))
+    }
+
+    /// Parse a board of cells like [`Board::parse`], pulling out the positions
+    /// of marker characters as it goes. See [`Board::try_parse_with_markers`].
+    pub fn parse_with_markers<M>(
+        input: &str,
+        markers: &[M],
+        fill: T,
+    ) -> Result<(Self, HashMap<char, Coord>), ParseBoardError<UnknownCell>>
+    where
+        M: Into<Marker> + Copy,
+        T: Clone,
+    {
+        Self::try_parse_with_markers(input, markers, fill, |c| T::from_char(c).ok_or(UnknownCell))
+    }
+}
+
+/// Display the board one character per cell, with a newline after each row
+impl<T> Display for Board<T>
+where
+    T: GridCell,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.row_slices() {
+            let line: String = row.iter().map(GridCell::to_char).collect();
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::grid_cell! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        enum Cell {
+            '.' => Empty,
+            '#' => Wall,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let input = "..#\n#..\n";
+        let board: Board<Cell> = Board::parse(input).unwrap();
+
+        assert_eq!(board[Coord(0, 2)], Cell::Wall);
+        assert_eq!(board.to_string(), input);
+        assert_eq!(Board::<char>::parse(input).unwrap(), Board::from_str(input));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Board::<Cell>::parse("..\n.x").unwrap_err();
+
+        assert_eq!(
+            err,
+            ParseBoardError::InvalidChar {
+                row: 1,
+                col: 1,
+                c: 'x',
+                error: UnknownCell,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid character 'x' at (1, 1): not a known cell"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_markers() {
+        let (board, markers) = Board::parse_with_markers("..#\n.^.", &['^'], Cell::Empty).unwrap();
+
+        assert_eq!(markers[&'^'], Coord(1, 1));
+        assert_eq!(board.to_string(), "..#\n...\n");
+    }
+}
@@ -0,0 +1,171 @@
+use super::{Board, Connectivity, Coord};
+use num::Zero;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+impl<T> Board<T> {
+    /// Find the fewest cardinal steps from any of the `sources` to every cell
+    /// on the board, moving only through cells where `passable` is true.
+    ///
+    /// Cells that can't be reached are `None`. Sources always have a distance
+    /// of zero, even if they aren't passable themselves, and any sources off
+    /// the board are ignored. On a toroidal board, steps wrap around the edges.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord};
+    ///
+    /// let board = Board::from_str(
+    ///     "S.#.\n\
+    ///      .##.\n\
+    ///      ....",
+    /// );
+    ///
+    /// let distances = board.distance_map([Coord(0, 0)], |c| *c != '#');
+    ///
+    /// assert_eq!(distances[Coord(0, 1)], Some(1));
+    /// assert_eq!(distances[Coord(0, 3)], Some(7));
+    /// assert_eq!(distances[Coord(0, 2)], None);
+    /// ```
+    pub fn distance_map<I, F>(&self, sources: I, passable: F) -> Board<Option<u32>>
+    where
+        I: IntoIterator<Item = Coord>,
+        F: Fn(&T) -> bool,
+    {
+        let mut distances = Board::from_size(self.size(), None).with_topology(self.topology);
+        let mut queue: VecDeque<(Coord, u32)> = VecDeque::new();
+
+        for source in sources {
+            if let Some(source) = self.normalize(&source) {
+                if distances[source].is_none() {
+                    distances[source] = Some(0);
+                    queue.push_back((source, 0));
+                }
+            }
+        }
+
+        while let Some((position, distance)) = queue.pop_front() {
+            for (neighbour, item) in self.neighbours(&position, Connectivity::Four) {
+                if distances[neighbour].is_none() && passable(item) {
+                    distances[neighbour] = Some(distance + 1);
+                    queue.push_back((neighbour, distance + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Find the lowest total cost from any of the `sources` to every cell on the
+    /// board, moving in cardinal steps. `cost` gives the cost of stepping onto
+    /// a cell, or `None` if the cell can't be entered.
+    ///
+    /// Cells that can't be reached are `None`. Sources always have a cost of
+    /// zero, and any sources off the board are ignored. On a toroidal board,
+    /// steps wrap around the edges.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord};
+    ///
+    /// let board = Board::from_str(
+    ///     "1163\n\
+    ///      1381\n\
+    ///      2136",
+    /// );
+    ///
+    /// let costs = board.weighted_distance_map([Coord(0, 0)], |c| c.to_digit(10));
+    ///
+    /// assert_eq!(costs[Coord(2, 3)], Some(13));
+    /// assert_eq!(costs[Coord(1, 1)], Some(4));
+    /// ```
+    pub fn weighted_distance_map<I, C, F>(&self, sources: I, cost: F) -> Board<Option<C>>
+    where
+        I: IntoIterator<Item = Coord>,
+        C: Zero + Ord + Copy,
+        F: Fn(&T) -> Option<C>,
+    {
+        let mut distances = Board::from_size(self.size(), None).with_topology(self.topology);
+        let mut heap = BinaryHeap::new();
+
+        for source in sources {
+            if let Some(source) = self.normalize(&source) {
+                distances[source] = Some(C::zero());
+                heap.push(Reverse((C::zero(), source.0, source.1)));
+            }
+        }
+
+        // Reverse ordering, so that the smallest cost is at the top (making this
+        // a min-heap). Coord isn't Ord, so the row and col are stored separately
+        while let Some(Reverse((distance, row, col))) = heap.pop() {
+            let position = Coord(row, col);
+
+            if distances[position].is_some_and(|best| distance > best) {
+                // Already found a cheaper way here
+                continue;
+            }
+
+            for (neighbour, item) in self.neighbours(&position, Connectivity::Four) {
+                let Some(step) = cost(item) else {
+                    continue;
+                };
+                let new_distance = distance + step;
+
+                if distances[neighbour].is_none_or(|best| new_distance < best) {
+                    distances[neighbour] = Some(new_distance);
+                    heap.push(Reverse((new_distance, neighbour.0, neighbour.1)));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_2d::Topology;
+
+    #[test]
+    fn test_multi_source_distance_map() {
+        let board = Board::from_str(
+            "a....\n\
+             .###.\n\
+             ....b",
+        );
+
+        let distances = board.distance_map([Coord(0, 0), Coord(2, 4), Coord(9, 9)], |c| *c != '#');
+
+        assert_eq!(distances[Coord(0, 0)], Some(0));
+        assert_eq!(distances[Coord(2, 4)], Some(0));
+        assert_eq!(distances[Coord(0, 4)], Some(2));
+        assert_eq!(distances[Coord(2, 1)], Some(3));
+        assert_eq!(distances[Coord(1, 2)], None);
+        assert_eq!(distances.iter().filter(|(_, d)| d.is_some()).count(), 12);
+    }
+
+    #[test]
+    fn test_toroidal_distance_map() {
+        let board = Board::from_str("......").with_topology(Topology::Toroidal);
+
+        let distances = board.distance_map([Coord(0, 0)], |_| true);
+
+        assert_eq!(distances[Coord(0, 5)], Some(1));
+        assert_eq!(distances[Coord(0, 3)], Some(3));
+    }
+
+    #[test]
+    fn test_weighted_matches_unweighted() {
+        let board = Board::from_str(
+            "..#...\n\
+             .##.#.\n\
+             ....#.",
+        );
+
+        let steps = board.distance_map([Coord(0, 0)], |c| *c != '#');
+        let weighted = board.weighted_distance_map([Coord(0, 0)], |c| (*c != '#').then_some(1u32));
+
+        assert_eq!(steps, weighted);
+    }
+}
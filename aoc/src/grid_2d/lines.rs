@@ -0,0 +1,213 @@
+use super::{Board, Coord, Dir};
+
+/// Iterator over the elements of a board along a straight line, created by
+/// [`Board::line`] and its relatives.
+///
+/// Yields each coordinate along with the element there, stopping at the edge
+/// of the board. On a toroidal board the line wraps around the edges, and stops
+/// once it gets back to where it started.
+pub struct Line<'a, T> {
+    board: &'a Board<T>,
+    position: Coord,
+    step: Coord,
+    /// Where the line started on the board, once the first element is yielded
+    start: Option<Coord>,
+}
+
+impl<'a, T> Iterator for Line<'a, T> {
+    type Item = (Coord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.board.normalize(&self.position)?;
+
+        match self.start {
+            None => self.start = Some(position),
+            // Wrapped all the way around
+            Some(start) if start == position => return None,
+            Some(_) => {}
+        }
+
+        self.position = position + self.step;
+
+        Some((position, &self.board[position]))
+    }
+}
+
+impl<T> Board<T> {
+    /// Iterate along a line from `start` (inclusive), moving by `step` each
+    /// time, until the edge of the board.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero, since the line would never end
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord};
+    ///
+    /// let board = Board::from_str(
+    ///     "a...\n\
+    ///      ..b.\n\
+    ///      ....\n\
+    ///      ...c",
+    /// );
+    ///
+    /// // Knight's move
+    /// let line: Vec<char> = board.line(Coord(0, 0), Coord(1, 2)).map(|(_, c)| *c).collect();
+    /// assert_eq!(line, vec!['a', 'b']);
+    /// ```
+    pub fn line(&self, start: Coord, step: Coord) -> Line<'_, T> {
+        assert_ne!(step, Coord(0, 0), "Line step must be non-zero");
+
+        Line {
+            board: self,
+            position: start,
+            step,
+            start: None,
+        }
+    }
+
+    /// Iterate from `start` (inclusive) in a direction, until the edge of the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Dir};
+    ///
+    /// let board = Board::from_str(
+    ///     "XMAS\n\
+    ///      .M..\n\
+    ///      ..A.\n\
+    ///      ...S",
+    /// );
+    ///
+    /// let word: String = board.ray(Coord(0, 0), Dir::SouthEast).map(|(_, c)| c).collect();
+    /// assert_eq!(word, "XMAS");
+    /// ```
+    pub fn ray(&self, start: Coord, dir: Dir) -> Line<'_, T> {
+        self.line(start, dir.into())
+    }
+
+    /// Iterate over row `i`, from left to right
+    pub fn row(&self, i: usize) -> Line<'_, T> {
+        self.ray((i, 0).into(), Dir::East)
+    }
+
+    /// Iterate over column `j`, from top to bottom
+    pub fn col(&self, j: usize) -> Line<'_, T> {
+        self.ray((0, j).into(), Dir::South)
+    }
+
+    /// Iterate over every row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    /// Iterate over every column, from left to right
+    pub fn cols(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.width).map(|j| self.col(j))
+    }
+
+    /// Iterate over every diagonal running from top left to bottom right.
+    ///
+    /// Diagonals start from the bottom left corner of the board, working up the
+    /// left edge and then along the top edge.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let left_edge = (0..self.height).rev().map(|row| (row, 0).into());
+        let top_edge = (1..self.width).map(|col| (0, col).into());
+
+        left_edge
+            .chain(top_edge)
+            .map(|start| self.ray(start, Dir::SouthEast))
+    }
+
+    /// Iterate over every anti-diagonal running from top right to bottom left.
+    ///
+    /// Anti-diagonals start from the top left corner of the board, working along
+    /// the top edge and then down the right edge.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let top_edge = (0..self.width).map(|col| (0, col).into());
+        let right_edge = (1..self.height).map(|row| (row, self.width.saturating_sub(1)).into());
+
+        top_edge
+            .chain(right_edge)
+            .map(|start| self.ray(start, Dir::SouthWest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_2d::Topology;
+
+    fn board() -> Board<char> {
+        Board::from_str(
+            "abc\n\
+             def",
+        )
+    }
+
+    fn chars<'a>(line: impl Iterator<Item = (Coord, &'a char)>) -> String {
+        line.map(|(_, c)| c).collect()
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let board = board();
+
+        assert_eq!(chars(board.row(1)), "def");
+        assert_eq!(chars(board.col(2)), "cf");
+        assert_eq!(chars(board.row(2)), "");
+        assert_eq!(
+            board.rows().map(chars).collect::<Vec<_>>(),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            board.cols().map(chars).collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+
+        let coords: Vec<Coord> = board.col(1).map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![Coord(0, 1), Coord(1, 1)]);
+    }
+
+    #[test]
+    fn test_rays() {
+        let board = board();
+
+        assert_eq!(chars(board.ray(Coord(1, 2), Dir::West)), "fed");
+        assert_eq!(chars(board.ray(Coord(1, 0), Dir::NorthEast)), "db");
+        assert_eq!(chars(board.ray(Coord(-1, 0), Dir::South)), "");
+        assert_eq!(chars(board.line(Coord(0, 0), Coord(0, 2))), "ac");
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let board = board();
+
+        assert_eq!(
+            board.diagonals().map(chars).collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            board.anti_diagonals().map(chars).collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn test_toroidal_rays() {
+        let board = board().with_topology(Topology::Toroidal);
+
+        assert_eq!(chars(board.ray(Coord(1, 2), Dir::East)), "fde");
+        assert_eq!(chars(board.ray(Coord(0, 0), Dir::NorthWest)), "afbdce");
+        assert_eq!(chars(board.line(Coord(-2, 4), Coord(0, 2))), "bac");
+
+        let coords: Vec<Coord> = board.ray(Coord(3, 5), Dir::South).map(|(c, _)| c).collect();
+        assert_eq!(coords, vec![Coord(1, 2), Coord(0, 2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_step() {
+        board().line(Coord(0, 0), Coord(0, 0));
+    }
+}
@@ -0,0 +1,251 @@
+use super::{Board, Coord};
+
+/// Iterator over the cells on a rasterized line between two coordinates,
+/// both inclusive, created by [`Coord::bresenham`].
+///
+/// Every step moves to a neighbouring cell (including diagonally), and the
+/// cells stay as close to the true line as possible.
+#[derive(Debug, Clone)]
+pub struct Bresenham {
+    position: Coord,
+    end: Coord,
+    /// Absolute difference in rows (negated) and cols
+    delta: Coord,
+    /// Sign of each step
+    step: Coord,
+    error: i32,
+    done: bool,
+}
+
+impl Iterator for Bresenham {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let current = self.position;
+
+        if current == self.end {
+            self.done = true;
+            return Some(current);
+        }
+
+        // Step along whichever axes keep us closest to the line
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.0 {
+            self.error += self.delta.0;
+            self.position.1 += self.step.1;
+        }
+        if doubled <= self.delta.1 {
+            self.error += self.delta.1;
+            self.position.0 += self.step.0;
+        }
+
+        Some(current)
+    }
+}
+
+/// Iterator over the exact lattice points on a line segment, both ends
+/// inclusive, created by [`Coord::lattice_points`].
+#[derive(Debug, Clone)]
+pub struct LatticePoints {
+    next: Option<Coord>,
+    end: Coord,
+    step: Coord,
+}
+
+impl Iterator for LatticePoints {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = (current != self.end).then(|| current + self.step);
+
+        Some(current)
+    }
+}
+
+impl Coord {
+    /// Rasterize the line from this coordinate to `end` using Bresenham's line
+    /// algorithm, including both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// let cells: Vec<Coord> = Coord(0, 0).bresenham(&Coord(2, 5)).collect();
+    ///
+    /// assert_eq!(
+    ///     cells,
+    ///     vec![Coord(0, 0), Coord(0, 1), Coord(1, 2), Coord(1, 3), Coord(2, 4), Coord(2, 5)]
+    /// );
+    /// ```
+    pub fn bresenham(&self, end: &Coord) -> Bresenham {
+        let delta = Coord(-(end.0 - self.0).abs(), (end.1 - self.1).abs());
+
+        Bresenham {
+            position: *self,
+            end: *end,
+            delta,
+            step: Coord((end.0 - self.0).signum(), (end.1 - self.1).signum()),
+            error: delta.0 + delta.1,
+            done: false,
+        }
+    }
+
+    /// Get the coordinates that lie exactly on the line segment from this
+    /// coordinate to `end`, including both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Coord;
+    ///
+    /// let points: Vec<Coord> = Coord(1, 1).lattice_points(&Coord(7, -3)).collect();
+    ///
+    /// assert_eq!(points, vec![Coord(1, 1), Coord(4, -1), Coord(7, -3)]);
+    /// ```
+    pub fn lattice_points(&self, end: &Coord) -> LatticePoints {
+        let step = if self == end {
+            Coord(0, 0)
+        } else {
+            (end - self).simplify()
+        };
+
+        LatticePoints {
+            next: Some(*self),
+            end: *end,
+            step,
+        }
+    }
+}
+
+impl<T> Board<T> {
+    /// Check whether there is a clear line of sight between two cells, where
+    /// `blocks` decides which elements block the view.
+    ///
+    /// The line is rasterized with [`Coord::bresenham`], and the two end cells
+    /// themselves are never checked. Returns `false` if the line leaves the
+    /// board.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord};
+    ///
+    /// let board = Board::from_str(
+    ///     "a...\n\
+    ///      .#..\n\
+    ///      ...b",
+    /// );
+    ///
+    /// assert!(!board.line_of_sight(&Coord(0, 0), &Coord(2, 2), |c| *c == '#'));
+    /// assert!(board.line_of_sight(&Coord(0, 0), &Coord(0, 3), |c| *c == '#'));
+    /// assert!(board.line_of_sight(&Coord(0, 3), &Coord(2, 3), |c| *c == '#'));
+    /// ```
+    pub fn line_of_sight<F>(&self, from: &Coord, to: &Coord, blocks: F) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        from.bresenham(to)
+            .filter(|c| c != from && c != to)
+            .all(|c| self.get(&c).is_some_and(|item| !blocks(item)))
+    }
+
+    /// Cast a ray from `start` (exclusive), moving by `step` each time, and
+    /// return the first cell that `hit` matches. Returns `None` if the ray
+    /// reaches the edge of the board first.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero, since the ray would never end
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Dir};
+    ///
+    /// let board = Board::from_str(
+    ///     ".L.L\n\
+    ///      ....\n\
+    ///      L..#",
+    /// );
+    ///
+    /// // The first seat visible in each direction
+    /// let seat = |c: &char| *c != '.';
+    ///
+    /// let start = Coord(0, 1);
+    ///
+    /// assert_eq!(board.cast_ray(start, Dir::East.into(), seat), Some((Coord(0, 3), &'L')));
+    /// assert_eq!(board.cast_ray(start, Dir::SouthEast.into(), seat), Some((Coord(2, 3), &'#')));
+    /// assert_eq!(board.cast_ray(start, Dir::SouthWest.into(), seat), None);
+    /// assert_eq!(board.cast_ray(start, Coord(1, 2), seat), None);
+    /// ```
+    pub fn cast_ray<F>(&self, start: Coord, step: Coord, mut hit: F) -> Option<(Coord, &T)>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.line(start, step).skip(1).find(|(_, item)| hit(item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bresenham_octants() {
+        let center = Coord(0, 0);
+
+        for end in Coord(0, 0).within_chebyshev(4).map(|(c, _)| c) {
+            let cells: Vec<Coord> = center.bresenham(&end).collect();
+
+            assert_eq!(cells.first(), Some(&center));
+            assert_eq!(cells.last(), Some(&end));
+            assert_eq!(cells.len() as i32, (end.0.abs()).max(end.1.abs()) + 1);
+
+            // Every step is to a neighbouring cell
+            for pair in cells.windows(2) {
+                let step = pair[1] - pair[0];
+                assert!(step != Coord(0, 0) && step.0.abs() <= 1 && step.1.abs() <= 1);
+            }
+
+            // Going backwards covers the same number of cells
+            assert_eq!(end.bresenham(&center).count(), cells.len());
+        }
+    }
+
+    #[test]
+    fn test_lattice_points() {
+        assert_eq!(
+            Coord(3, 3).lattice_points(&Coord(3, 3)).collect::<Vec<_>>(),
+            vec![Coord(3, 3)]
+        );
+        assert_eq!(Coord(0, 0).lattice_points(&Coord(0, 5)).count(), 6);
+        assert_eq!(Coord(0, 0).lattice_points(&Coord(6, -4)).count(), 3);
+        assert_eq!(Coord(0, 0).lattice_points(&Coord(7, 3)).count(), 2);
+
+        // Lattice points are always on the rasterized line too
+        let end = Coord(-9, 6);
+        let raster: Vec<Coord> = Coord(0, 0).bresenham(&end).collect();
+        assert!(Coord(0, 0)
+            .lattice_points(&end)
+            .all(|c| raster.contains(&c)));
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let board = Board::from_str(
+            "a..#\n\
+             ....\n\
+             ..#.",
+        );
+        let wall = |c: &char| *c == '#';
+
+        // The ends can be walls themselves
+        assert!(board.line_of_sight(&Coord(0, 0), &Coord(0, 3), wall));
+        assert!(board.line_of_sight(&Coord(0, 0), &Coord(0, 0), wall));
+        assert!(!board.line_of_sight(&Coord(2, 3), &Coord(2, 0), wall));
+        assert!(board.line_of_sight(&Coord(0, 3), &Coord(2, 1), wall));
+        assert!(!board.line_of_sight(&Coord(0, 0), &Coord(0, 5), wall));
+    }
+}
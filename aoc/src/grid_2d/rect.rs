@@ -0,0 +1,330 @@
+use super::{Board, Coord};
+
+/// A rectangle of cells between two corners, both inclusive
+///
+/// A `Rect` always contains at least one cell, so operations that could give
+/// an empty rectangle (like [`Rect::intersection`]) return an `Option`. The
+/// corners can only be set through constructors that keep them in order.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub struct Rect {
+    min: Coord,
+    max: Coord,
+}
+
+impl Rect {
+    /// Create a rectangle between any two opposite corners
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Coord, Rect};
+    ///
+    /// let rect = Rect::new(Coord(3, 0), Coord(1, 4));
+    ///
+    /// assert_eq!(rect.min(), Coord(1, 0));
+    /// assert_eq!(rect.max(), Coord(3, 4));
+    /// assert_eq!(rect.size(), (3, 5));
+    /// ```
+    pub fn new(a: Coord, b: Coord) -> Self {
+        Rect {
+            min: Coord(a.0.min(b.0), a.1.min(b.1)),
+            max: Coord(a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    /// Create a rectangle with its top left corner at `Coord(0, 0)`, covering
+    /// a board of the given (rows, cols) size.
+    ///
+    /// Returns `None` if the size is empty.
+    pub fn from_size<S>(size: S) -> Option<Self>
+    where
+        S: Into<Coord>,
+    {
+        let size = size.into();
+
+        if size.0 <= 0 || size.1 <= 0 {
+            return None;
+        }
+
+        Some(Rect {
+            min: Coord(0, 0),
+            max: size - Coord(1, 1),
+        })
+    }
+
+    /// Create a square containing every cell within `radius` steps of the
+    /// center, including diagonal steps
+    pub fn around(center: Coord, radius: u32) -> Self {
+        let radius = radius as i32;
+
+        Rect {
+            min: center - Coord(radius, radius),
+            max: center + Coord(radius, radius),
+        }
+    }
+
+    /// Get the smallest rectangle containing every coordinate, or `None` if
+    /// there aren't any coordinates
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Coord, Rect};
+    ///
+    /// let coords = [Coord(2, -1), Coord(0, 3), Coord(1, 1)];
+    ///
+    /// assert_eq!(
+    ///     Rect::bounding_box(&coords),
+    ///     Some(Rect::new(Coord(0, -1), Coord(2, 3)))
+    /// );
+    /// assert_eq!(Rect::bounding_box(&[]), None);
+    /// ```
+    pub fn bounding_box<'a, I>(coords: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Coord>,
+    {
+        let mut coords = coords.into_iter();
+        let first = *coords.next()?;
+
+        Some(coords.fold(Rect::new(first, first), |rect, c| rect.expand_to(c)))
+    }
+
+    /// Top left corner (inclusive)
+    pub fn min(&self) -> Coord {
+        self.min
+    }
+
+    /// Bottom right corner (inclusive)
+    pub fn max(&self) -> Coord {
+        self.max
+    }
+
+    /// Get the (rows, cols) size of the rectangle
+    pub fn size(&self) -> (usize, usize) {
+        (self.height(), self.width())
+    }
+
+    /// Number of rows in the rectangle
+    pub fn height(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    /// Number of columns in the rectangle
+    pub fn width(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    /// Number of cells in the rectangle
+    pub fn area(&self) -> usize {
+        self.height() * self.width()
+    }
+
+    /// Check whether a coordinate is inside the rectangle
+    pub fn contains(&self, c: &Coord) -> bool {
+        (self.min.0..=self.max.0).contains(&c.0) && (self.min.1..=self.max.1).contains(&c.1)
+    }
+
+    /// Check whether another rectangle is entirely inside this one
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// Get the cells that are in both rectangles, or `None` if they don't
+    /// overlap
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Coord, Rect};
+    ///
+    /// let a = Rect::new(Coord(0, 0), Coord(4, 4));
+    /// let b = Rect::new(Coord(3, -2), Coord(8, 1));
+    ///
+    /// assert_eq!(a.intersection(&b), Some(Rect::new(Coord(3, 0), Coord(4, 1))));
+    /// assert_eq!(a.intersection(&Rect::new(Coord(5, 5), Coord(6, 6))), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Coord(self.min.0.max(other.min.0), self.min.1.max(other.min.1));
+        let max = Coord(self.max.0.min(other.max.0), self.max.1.min(other.max.1));
+
+        (min.0 <= max.0 && min.1 <= max.1).then_some(Rect { min, max })
+    }
+
+    /// Get the smallest rectangle containing both rectangles
+    pub fn union(&self, other: &Rect) -> Rect {
+        self.expand_to(&other.min).expand_to(&other.max)
+    }
+
+    /// Get the smallest rectangle containing this one and a coordinate
+    pub fn expand_to(&self, c: &Coord) -> Rect {
+        Rect {
+            min: Coord(self.min.0.min(c.0), self.min.1.min(c.1)),
+            max: Coord(self.max.0.max(c.0), self.max.1.max(c.1)),
+        }
+    }
+
+    /// Grow the rectangle by `n` cells on every side
+    pub fn grow(&self, n: u32) -> Rect {
+        let n = n as i32;
+
+        Rect {
+            min: self.min - Coord(n, n),
+            max: self.max + Coord(n, n),
+        }
+    }
+
+    /// Iterate over every coordinate in the rectangle, row by row
+    pub fn iter(&self) -> Coords {
+        Coords {
+            rect: *self,
+            next: Some(self.min),
+        }
+    }
+
+    /// Split the rectangle into (top left, top right, bottom left, bottom right)
+    /// quadrants.
+    ///
+    /// If the height or width is odd, the middle row or column isn't in any of
+    /// the quadrants. Returns `None` if that would leave the quadrants empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Coord, Rect};
+    ///
+    /// let rect = Rect::from_size((7, 11)).unwrap();
+    /// let quadrants = rect.quadrants().unwrap();
+    ///
+    /// assert_eq!(quadrants[0], Rect::new(Coord(0, 0), Coord(2, 4)));
+    /// assert_eq!(quadrants[3], Rect::new(Coord(4, 6), Coord(6, 10)));
+    /// assert!(!quadrants.iter().any(|q| q.contains(&Coord(3, 5))));
+    /// ```
+    pub fn quadrants(&self) -> Option<[Rect; 4]> {
+        let half_height = self.height() as i32 / 2;
+        let half_width = self.width() as i32 / 2;
+
+        if half_height == 0 || half_width == 0 {
+            return None;
+        }
+
+        let top = (self.min.0, self.min.0 + half_height - 1);
+        let bottom = (self.max.0 - half_height + 1, self.max.0);
+        let left = (self.min.1, self.min.1 + half_width - 1);
+        let right = (self.max.1 - half_width + 1, self.max.1);
+
+        Some(
+            [(top, left), (top, right), (bottom, left), (bottom, right)].map(|(rows, cols)| Rect {
+                min: Coord(rows.0, cols.0),
+                max: Coord(rows.1, cols.1),
+            }),
+        )
+    }
+}
+
+impl IntoIterator for Rect {
+    type Item = Coord;
+    type IntoIter = Coords;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the coordinates in a [`Rect`], row by row
+#[derive(Debug, Clone)]
+pub struct Coords {
+    rect: Rect,
+    next: Option<Coord>,
+}
+
+impl Iterator for Coords {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        self.next = if current.1 < self.rect.max.1 {
+            Some(Coord(current.0, current.1 + 1))
+        } else if current.0 < self.rect.max.0 {
+            Some(Coord(current.0 + 1, self.rect.min.1))
+        } else {
+            None
+        };
+
+        Some(current)
+    }
+}
+
+impl<T> Board<T> {
+    /// Get the rectangle covering the whole board, or `None` if the board is
+    /// empty
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::from_size(self.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_set_operations() {
+        let a = Rect::new(Coord(0, 0), Coord(2, 3));
+        let b = Rect::new(Coord(1, 2), Coord(5, 5));
+
+        assert_eq!(a.area(), 12);
+        assert_eq!(b.size(), (5, 4));
+        assert_eq!(a.union(&b), Rect::new(Coord(0, 0), Coord(5, 5)));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Coord(1, 2), Coord(2, 3)))
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+
+        assert!(a.union(&b).contains_rect(&a));
+        assert!(!a.contains_rect(&b));
+        assert!(a.contains(&Coord(2, 3)));
+        assert!(!a.contains(&Coord(3, 3)));
+
+        assert_eq!(
+            Rect::around(Coord(5, 5), 2).grow(1),
+            Rect::around(Coord(5, 5), 3)
+        );
+        assert_eq!(Rect::around(Coord(5, 5), 0).area(), 1);
+        assert_eq!(Rect::from_size((0, 3)), None);
+    }
+
+    #[test]
+    fn test_rect_corners_ordered() {
+        // Corners given the wrong way round are swapped, so sizes can't go
+        // negative
+        let rect = Rect::new(Coord(5, -2), Coord(-5, 2));
+
+        assert_eq!(rect.min(), Coord(-5, -2));
+        assert_eq!(rect.max(), Coord(5, 2));
+        assert_eq!(rect.size(), (11, 5));
+        assert_eq!(rect.area(), 55);
+        assert_eq!(rect, Rect::new(Coord(-5, 2), Coord(5, -2)));
+    }
+
+    #[test]
+    fn test_rect_iteration() {
+        let rect = Rect::new(Coord(-1, 4), Coord(0, 6));
+        let coords: Vec<Coord> = rect.into_iter().collect();
+
+        assert_eq!(coords.len(), rect.area());
+        assert_eq!(coords[0], rect.min());
+        assert_eq!(coords[1], Coord(-1, 5));
+        assert_eq!(coords.last(), Some(&rect.max()));
+        assert_eq!(Rect::bounding_box(&coords), Some(rect));
+    }
+
+    #[test]
+    fn test_quadrants() {
+        // Even sizes split exactly in half
+        let rect = Rect::from_size((4, 2)).unwrap();
+        let quadrants = rect.quadrants().unwrap();
+
+        assert_eq!(quadrants.iter().map(Rect::area).sum::<usize>(), rect.area());
+        assert_eq!(quadrants[2], Rect::new(Coord(2, 0), Coord(3, 0)));
+
+        // Too thin to have any quadrants
+        assert_eq!(Rect::from_size((1, 5)).unwrap().quadrants(), None);
+    }
+}
@@ -0,0 +1,751 @@
+use super::{Board, Coord, Dir, Rect, Topology};
+use std::collections::HashSet;
+
+/// Which neighbouring cells count as connected to a cell
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum Connectivity {
+    /// Only cells in the cardinal directions are connected
+    Four,
+    /// Cells in the cardinal and diagonal directions are connected
+    Eight,
+}
+
+impl Connectivity {
+    /// The directions to the connected neighbours of a cell
+    pub fn dirs(self) -> &'static [Dir] {
+        const CARDINAL: [Dir; 4] = Dir::cardinal();
+        const ALL: [Dir; 8] = Dir::all();
+
+        match self {
+            Connectivity::Four => &CARDINAL,
+            Connectivity::Eight => &ALL,
+        }
+    }
+
+    /// Get the connected neighbours of a coordinate
+    pub fn neighbours(self, c: &Coord) -> impl Iterator<Item = Coord> {
+        let c = *c;
+
+        self.dirs().iter().map(move |&dir| c + dir)
+    }
+}
+
+/// A connected region of cells on a board.
+///
+/// On a [`Topology::Toroidal`] board, a region can join up across the edges of
+/// the board. Its perimeter, sides and corners take that into account, but the
+/// holes and outline of a region that wraps around aren't defined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Label of the region in [`Regions::labels`]
+    pub label: usize,
+    /// Every cell in the region, in the order they were found
+    pub cells: Vec<Coord>,
+    /// Bounding box of the region
+    pub bounds: Rect,
+    /// How the cells of the region are connected to each other
+    pub connectivity: Connectivity,
+    /// Topology of the board the region was found on
+    pub topology: Topology,
+    /// Size of the board the region was found on, as (rows, cols)
+    pub board_size: (usize, usize),
+}
+
+impl Region {
+    /// Number of cells in the region
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Get the smallest rectangle containing the region
+    pub fn bounding_box(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Length of the region's perimeter, including around any holes.
+    ///
+    /// This is the number of cell edges between a cell in the region and
+    /// one outside of it.
+    pub fn perimeter(&self) -> usize {
+        self.boundary_edges().len()
+    }
+
+    /// Number of straight sides around the region, including around any holes.
+    ///
+    /// Sides running in opposite directions along the same line (such as either
+    /// side of a one cell wide gap) are counted separately.
+    pub fn sides(&self) -> usize {
+        let wraps = self.wraps();
+        let edges = self.boundary_edges();
+        let mut remaining = edges.clone();
+        let mut sides = 0;
+
+        // Follow a side from one of its edges, removing all of them
+        let remove_side = |remaining: &mut HashSet<(Coord, Dir)>, (mut vertex, dir)| {
+            while remaining.remove(&(vertex, dir)) {
+                vertex = self.vertex(vertex + dir, wraps);
+            }
+        };
+
+        // A side starts at any edge that doesn't continue on from an edge
+        // in the same direction
+        for &(vertex, dir) in edges.iter() {
+            if !edges.contains(&(self.vertex(vertex + dir.rotate_180(), wraps), dir)) {
+                sides += 1;
+                remove_side(&mut remaining, (vertex, dir));
+            }
+        }
+
+        // Anything left is a side that goes all the way around a toroidal
+        // board, so doesn't start anywhere
+        while let Some(&edge) = remaining.iter().next() {
+            sides += 1;
+            remove_side(&mut remaining, edge);
+        }
+
+        sides
+    }
+
+    /// Number of corners around the region, including around any holes.
+    ///
+    /// Since every side starts and ends at a corner, this is the same as
+    /// [`Region::sides`], unless a side goes all the way around a toroidal
+    /// board without any corners.
+    pub fn corners(&self) -> usize {
+        let cells = self.cell_set();
+        let contains = |c: Coord| cells.contains(&self.wrap(c));
+        let mut corners = 0;
+
+        for position in self.cells.iter() {
+            // Direction of the neighbour and whether it is in the region
+            let neighbours: [(Dir, bool); 4] =
+                Dir::cardinal().map(|dir| (dir, contains(position + dir)));
+
+            for i in 0..4 {
+                let (dir_a, in_a) = neighbours[i];
+                let (dir_b, in_b) = neighbours[(i + 1) % 4];
+
+                if !in_a && !in_b {
+                    // Outer corner: a and b are both outside the region
+                    corners += 1;
+                } else if in_a && in_b {
+                    // Inner corner: a and b are both in the region, but the
+                    // diagonal between them isn't
+                    if !contains(position + dir_a + dir_b) {
+                        corners += 1;
+                    }
+                }
+            }
+        }
+
+        corners
+    }
+
+    /// Number of holes in the region, which are groups of cells not in the
+    /// region but completely enclosed by it.
+    ///
+    /// Holes are connected the opposite way to the region: in a
+    /// [`Connectivity::Four`] region, two hole cells that only touch diagonally
+    /// form a single hole, since the region doesn't pass between them.
+    ///
+    /// Returns `None` if the region [wraps](Region::wraps) around a toroidal
+    /// board.
+    pub fn holes(&self) -> Option<usize> {
+        if self.wraps() {
+            return None;
+        }
+
+        let cells = self.cell_set();
+        let hole_connectivity = match self.connectivity {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        };
+
+        // Search the bounding box, with a 1 cell border around it that is
+        // definitely outside of the region
+        let bounds = self.bounds.grow(1);
+        let (min, max) = (bounds.min(), bounds.max());
+        let in_bounds = |c: &Coord| bounds.contains(c);
+        let on_border = |c: &Coord| c.0 == min.0 || c.0 == max.0 || c.1 == min.1 || c.1 == max.1;
+
+        let mut visited: HashSet<Coord> = HashSet::new();
+        let mut holes = 0;
+
+        for row in min.0..=max.0 {
+            for col in min.1..=max.1 {
+                let start = Coord(row, col);
+                if cells.contains(&start) || !visited.insert(start) {
+                    continue;
+                }
+
+                // Flood fill this group of cells outside the region, to see if
+                // it escapes to the border
+                let mut enclosed = true;
+                let mut queue: Vec<Coord> = vec![start];
+
+                while let Some(position) = queue.pop() {
+                    enclosed &= !on_border(&position);
+
+                    for neighbour in hole_connectivity.neighbours(&position) {
+                        if in_bounds(&neighbour)
+                            && !cells.contains(&neighbour)
+                            && visited.insert(neighbour)
+                        {
+                            queue.push(neighbour);
+                        }
+                    }
+                }
+
+                if enclosed {
+                    holes += 1;
+                }
+            }
+        }
+
+        Some(holes)
+    }
+
+    /// Get the outer boundary of the region as a polygon.
+    ///
+    /// Vertices are the corners of cells, where vertex `Coord(r, c)` is the top
+    /// left corner of cell `Coord(r, c)`. The polygon goes clockwise starting from
+    /// the top left corner of the region, and only includes vertices where the
+    /// boundary turns. Holes are not included.
+    ///
+    /// Returns `None` if the region [wraps](Region::wraps) around a toroidal
+    /// board.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Connectivity, Coord};
+    ///
+    /// let board = Board::from_str(
+    ///     "AB\n\
+    ///      AA",
+    /// );
+    ///
+    /// let regions = board.regions(Connectivity::Four);
+    /// let a = regions.region_at(&Coord(0, 0)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a.outline().unwrap(),
+    ///     vec![
+    ///         Coord(0, 0),
+    ///         Coord(0, 1),
+    ///         Coord(1, 1),
+    ///         Coord(1, 2),
+    ///         Coord(2, 2),
+    ///         Coord(2, 0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn outline(&self) -> Option<Vec<Coord>> {
+        if self.wraps() {
+            return None;
+        }
+
+        let edges = self.boundary_edges();
+
+        // When the boundary touches itself at a vertex, which way to go depends
+        // on whether the cells on either side of it are connected. Turning right
+        // wraps tightly around the current cell, keeping them apart.
+        let preferences = |dir: Dir| match self.connectivity {
+            Connectivity::Four => [dir.rotate_right(), dir, dir.rotate_left()],
+            Connectivity::Eight => [dir.rotate_left(), dir, dir.rotate_right()],
+        };
+
+        // The top left corner of the top-most, left-most cell is always a corner
+        // of the outer boundary, with an edge heading east along the top
+        let start = *self.cells.iter().min_by_key(|c| (c.0, c.1)).unwrap();
+        let mut outline = vec![start];
+        let mut position = start;
+        let mut dir = Dir::East;
+
+        loop {
+            position = position + dir;
+
+            if position == start {
+                break;
+            }
+
+            let next_dir = preferences(dir)
+                .into_iter()
+                .find(|d| edges.contains(&(position, *d)))
+                .expect("Region boundary should be closed");
+
+            if next_dir != dir {
+                outline.push(position);
+            }
+
+            dir = next_dir;
+        }
+
+        Some(outline)
+    }
+
+    /// Whether the region is on a toroidal board, and joins up with itself
+    /// across the edges of the board
+    pub fn wraps(&self) -> bool {
+        if self.topology == Topology::Bounded {
+            return false;
+        }
+
+        let cells = self.cell_set();
+        let (rows, cols) = self.board_size;
+        let on_board =
+            |c: &Coord| c.0 >= 0 && (c.0 as usize) < rows && c.1 >= 0 && (c.1 as usize) < cols;
+
+        self.cells.iter().any(|position| {
+            self.connectivity
+                .neighbours(position)
+                .any(|n| !on_board(&n) && cells.contains(&self.wrap(n)))
+        })
+    }
+
+    fn cell_set(&self) -> HashSet<Coord> {
+        self.cells.iter().copied().collect()
+    }
+
+    /// Get the cell on the board that a coordinate refers to, wrapping around
+    /// the edges of a toroidal board
+    fn wrap(&self, c: Coord) -> Coord {
+        match self.topology {
+            Topology::Bounded => c,
+            Topology::Toroidal => c.wrap_to_size(self.board_size),
+        }
+    }
+
+    /// Vertices of a region that wraps around are wrapped too, so that sides
+    /// carry on across the edges of the board
+    fn vertex(&self, c: Coord, wraps: bool) -> Coord {
+        if wraps {
+            self.wrap(c)
+        } else {
+            c
+        }
+    }
+
+    /// Get every edge between a cell in the region and one outside of it, as the
+    /// vertex the edge starts from and the direction it goes in.
+    ///
+    /// Edges are directed clockwise around the region, so the region is always
+    /// on the right.
+    fn boundary_edges(&self) -> HashSet<(Coord, Dir)> {
+        let cells = self.cell_set();
+        let wraps = self.wraps();
+        let mut edges: HashSet<(Coord, Dir)> = HashSet::new();
+
+        for &position in self.cells.iter() {
+            let Coord(row, col) = position;
+
+            for dir in Dir::cardinal() {
+                if cells.contains(&self.wrap(position + dir)) {
+                    continue;
+                }
+
+                let edge = match dir {
+                    Dir::North => (Coord(row, col), Dir::East),
+                    Dir::East => (Coord(row, col + 1), Dir::South),
+                    Dir::South => (Coord(row + 1, col + 1), Dir::West),
+                    Dir::West => (Coord(row + 1, col), Dir::North),
+                    _ => unreachable!(),
+                };
+
+                edges.insert((self.vertex(edge.0, wraps), edge.1));
+            }
+        }
+
+        edges
+    }
+}
+
+/// The result of labelling the connected regions of a board
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// A board of the same size as the original, with the label of the region
+    /// each cell belongs to. Cells that aren't part of any region are `None`.
+    pub labels: Board<Option<usize>>,
+    /// Every region, indexed by label
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Get the region a coordinate belongs to, if any
+    pub fn region_at(&self, c: &Coord) -> Option<&Region> {
+        self.labels
+            .get(c)
+            .copied()
+            .flatten()
+            .map(|label| &self.regions[label])
+    }
+
+    /// Number of regions found
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+impl<T> Board<T> {
+    /// Label the connected regions of the board, where neighbouring cells are
+    /// in the same region if they're equal.
+    ///
+    /// Every cell on the board ends up in exactly one region.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Connectivity, Coord, Rect};
+    ///
+    /// let board = Board::from_str(
+    ///     "AAB\n\
+    ///      ABA\n\
+    ///      CCA",
+    /// );
+    ///
+    /// let regions = board.regions(Connectivity::Four);
+    /// assert_eq!(regions.len(), 5);
+    ///
+    /// let a = regions.region_at(&Coord(0, 0)).unwrap();
+    /// assert_eq!(a.area(), 3);
+    /// assert_eq!(a.bounding_box(), Rect::new(Coord(0, 0), Coord(1, 1)));
+    ///
+    /// // The As and Bs on the right touch the others diagonally
+    /// let regions = board.regions(Connectivity::Eight);
+    /// assert_eq!(regions.len(), 3);
+    /// assert_eq!(regions.region_at(&Coord(0, 0)).unwrap().area(), 5);
+    /// ```
+    pub fn regions(&self, connectivity: Connectivity) -> Regions
+    where
+        T: Eq,
+    {
+        self.regions_by(connectivity, |a, b| a == b)
+    }
+
+    /// Label the connected regions of the board, where neighbouring cells are
+    /// in the same region if `same_region` returns true for them.
+    ///
+    /// Every cell on the board ends up in exactly one region.
+    pub fn regions_by<F>(&self, connectivity: Connectivity, same_region: F) -> Regions
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        self.label_regions(connectivity, |_| true, same_region)
+    }
+
+    /// Label the connected regions of cells matching `predicate`. Any
+    /// neighbouring cells that both match are in the same region.
+    ///
+    /// Cells that don't match aren't part of any region.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Connectivity};
+    ///
+    /// let board = Board::from_str(
+    ///     "##..\n\
+    ///      #..#\n\
+    ///      ...#",
+    /// );
+    ///
+    /// let regions = board.regions_where(Connectivity::Four, |c| *c == '#');
+    /// let largest = regions.iter().map(|r| r.area()).max();
+    ///
+    /// assert_eq!(regions.len(), 2);
+    /// assert_eq!(largest, Some(3));
+    /// ```
+    pub fn regions_where<P>(&self, connectivity: Connectivity, predicate: P) -> Regions
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.label_regions(connectivity, predicate, |_, _| true)
+    }
+
+    /// Flood fill every region, considering only cells matching `include`
+    fn label_regions<P, F>(&self, connectivity: Connectivity, include: P, same_region: F) -> Regions
+    where
+        P: Fn(&T) -> bool,
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut labels: Board<Option<usize>> = Board::from_size(self.size(), None);
+        let mut regions: Vec<Region> = Vec::new();
+
+        for (start, item) in self.iter() {
+            if labels[start].is_some() || !include(item) {
+                continue;
+            }
+
+            let label = regions.len();
+            let mut region = Region {
+                label,
+                cells: Vec::new(),
+                bounds: Rect::new(start, start),
+                connectivity,
+                topology: self.topology(),
+                board_size: self.size(),
+            };
+
+            // Basic DFS search of connected cells
+            labels[start] = Some(label);
+            let mut queue: Vec<Coord> = vec![start];
+
+            while let Some(position) = queue.pop() {
+                region.cells.push(position);
+                region.bounds = region.bounds.expand_to(&position);
+
+                let current = &self[position];
+
+                for (neighbour, next) in self.neighbours(&position, connectivity) {
+                    if labels[neighbour].is_some() || !include(next) || !same_region(current, next)
+                    {
+                        continue;
+                    }
+
+                    labels[neighbour] = Some(label);
+                    queue.push(neighbour);
+                }
+            }
+
+            regions.push(region);
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_partition_board() {
+        let board = Board::from_str(
+            "RRRRIICCFF\n\
+             RRRRIICCCF\n\
+             VVRRRCCFFF\n\
+             VVRCCCJFFF\n\
+             VVVVCJJCFE\n\
+             VVIVCCJJEE\n\
+             VVIIICJJEE\n\
+             MIIIIIJJEE\n\
+             MIIISIJEEE\n\
+             MMMISSJEEE",
+        );
+
+        let regions = board.regions(Connectivity::Four);
+
+        // From the day 12 example, where the two I and C regions are separate
+        assert_eq!(regions.len(), 11);
+        assert_eq!(regions.iter().map(Region::area).sum::<usize>(), 100);
+
+        for region in regions.iter() {
+            let kind = board[region.cells[0]];
+            assert!(region.cells.iter().all(|c| board[*c] == kind));
+            assert!(region
+                .cells
+                .iter()
+                .all(|c| regions.labels[*c] == Some(region.label)));
+        }
+
+        let r = regions.region_at(&Coord(0, 0)).unwrap();
+        assert_eq!(r.area(), 12);
+        assert_eq!(r.bounding_box(), Rect::new(Coord(0, 0), Coord(3, 4)));
+    }
+
+    #[test]
+    fn test_regions_where() {
+        let board = Board::from_str(
+            "#..#\n\
+             .#..\n\
+             ...#",
+        );
+
+        let four = board.regions_where(Connectivity::Four, |c| *c == '#');
+        assert_eq!(four.len(), 4);
+        assert_eq!(four.labels[Coord(0, 1)], None);
+
+        // Diagonals join the top left pair together
+        let eight = board.regions_where(Connectivity::Eight, |c| *c == '#');
+        assert_eq!(eight.len(), 3);
+        assert_eq!(eight.region_at(&Coord(1, 1)).unwrap().area(), 2);
+        assert!(eight.region_at(&Coord(0, 1)).is_none());
+    }
+
+    /// Total price of fencing every region, from day 12
+    fn fence_prices(input: &str) -> (usize, usize) {
+        let board = Board::from_str(input);
+        let regions = board.regions(Connectivity::Four);
+
+        let by_perimeter = regions.iter().map(|r| r.area() * r.perimeter()).sum();
+        let by_sides = regions.iter().map(|r| r.area() * r.sides()).sum();
+
+        (by_perimeter, by_sides)
+    }
+
+    #[test]
+    fn test_perimeter_and_sides() {
+        assert_eq!(fence_prices("AAAA\nBBCD\nBBCC\nEEEC"), (140, 80));
+        assert_eq!(
+            fence_prices("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO"),
+            (772, 436)
+        );
+        assert_eq!(
+            fence_prices(
+                "RRRRIICCFF\n\
+                 RRRRIICCCF\n\
+                 VVRRRCCFFF\n\
+                 VVRCCCJFFF\n\
+                 VVVVCJJCFE\n\
+                 VVIVCCJJEE\n\
+                 VVIIICJJEE\n\
+                 MIIIIIJJEE\n\
+                 MIIISIJEEE\n\
+                 MMMISSJEEE"
+            ),
+            (1930, 1206)
+        );
+    }
+
+    #[test]
+    fn test_concave_region() {
+        let board = Board::from_str("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE");
+        let regions = board.regions(Connectivity::Four);
+        let e = regions.region_at(&Coord(0, 0)).unwrap();
+
+        assert_eq!(e.area(), 17);
+        assert_eq!(e.perimeter(), 36);
+        assert_eq!(e.sides(), 12);
+        assert_eq!(e.corners(), 12);
+        assert_eq!(e.holes(), Some(0));
+        assert_eq!(
+            e.outline().unwrap(),
+            vec![
+                Coord(0, 0),
+                Coord(0, 5),
+                Coord(1, 5),
+                Coord(1, 1),
+                Coord(2, 1),
+                Coord(2, 5),
+                Coord(3, 5),
+                Coord(3, 1),
+                Coord(4, 1),
+                Coord(4, 5),
+                Coord(5, 5),
+                Coord(5, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enclosed_regions() {
+        let board = Board::from_str("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        let regions = board.regions(Connectivity::Four);
+        let o = regions.region_at(&Coord(0, 0)).unwrap();
+
+        assert_eq!(o.holes(), Some(4));
+        assert_eq!(o.sides(), 20);
+        assert_eq!(o.corners(), 20);
+        // Only the outer boundary
+        assert_eq!(
+            o.outline().unwrap(),
+            vec![Coord(0, 0), Coord(0, 5), Coord(5, 5), Coord(5, 0)]
+        );
+
+        let x = regions.region_at(&Coord(1, 1)).unwrap();
+        assert_eq!(x.holes(), Some(0));
+        assert_eq!(x.sides(), 4);
+
+        // The two B blocks touch diagonally, which the A region doesn't pass
+        // between, so they form a single hole
+        let board = Board::from_str("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA");
+        let regions = board.regions(Connectivity::Four);
+        let a = regions.region_at(&Coord(0, 0)).unwrap();
+
+        assert_eq!(a.holes(), Some(1));
+        assert_eq!(a.sides(), 12);
+        assert_eq!(a.corners(), 12);
+    }
+
+    #[test]
+    fn test_outline_pinch() {
+        // A ring that is only closed by a diagonal
+        let board = Board::from_str(
+            "###.\n\
+             #..#\n\
+             ####",
+        );
+
+        // With diagonals connected the gap is enclosed
+        let regions = board.regions_where(Connectivity::Eight, |c| *c == '#');
+        let ring = &regions.regions[0];
+        assert_eq!(ring.holes(), Some(1));
+        assert_eq!(
+            ring.outline().unwrap(),
+            vec![
+                Coord(0, 0),
+                Coord(0, 3),
+                Coord(1, 3),
+                Coord(1, 4),
+                Coord(3, 4),
+                Coord(3, 0),
+            ]
+        );
+
+        // Otherwise the outline passes through the gap and into the middle
+        let board = Board::from_str(
+            "###.\n\
+             #..#\n\
+             ####",
+        );
+        let regions = board.regions_where(Connectivity::Four, |c| *c == '#');
+        let ring = &regions.regions[0];
+        assert_eq!(ring.holes(), Some(0));
+        assert_eq!(ring.outline().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_toroidal_geometry() {
+        // A full row joins up with itself, so its only edges are above and
+        // below it, and they go all the way around without any corners
+        let board = Board::from_str("###\n...\n...").with_topology(Topology::Toroidal);
+        let regions = board.regions_where(Connectivity::Four, |c| *c == '#');
+        let row = &regions.regions[0];
+
+        assert!(row.wraps());
+        assert_eq!(row.perimeter(), 6);
+        assert_eq!(row.sides(), 2);
+        assert_eq!(row.corners(), 0);
+        assert_eq!(row.holes(), None);
+        assert_eq!(row.outline(), None);
+
+        // The corners of the board make a 2x2 square across the edges
+        let board = Board::from_str("#..#\n....\n#..#").with_topology(Topology::Toroidal);
+        let regions = board.regions_where(Connectivity::Four, |c| *c == '#');
+        let square = &regions.regions[0];
+
+        assert!(square.wraps());
+        assert_eq!(square.perimeter(), 8);
+        assert_eq!(square.sides(), 4);
+        assert_eq!(square.corners(), 4);
+
+        // A region that doesn't reach across the edges works like it would on
+        // a bounded board
+        let board = Board::from_str("#..\n#..\n...").with_topology(Topology::Toroidal);
+        let regions = board.regions_where(Connectivity::Four, |c| *c == '#');
+        let column = &regions.regions[0];
+
+        assert!(!column.wraps());
+        assert_eq!(column.perimeter(), 6);
+        assert_eq!(column.holes(), Some(0));
+        assert_eq!(
+            column.outline().unwrap(),
+            vec![Coord(0, 0), Coord(0, 1), Coord(2, 1), Coord(2, 0)]
+        );
+    }
+}
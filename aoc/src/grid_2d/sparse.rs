@@ -0,0 +1,231 @@
+use super::{grid_with_axes, Board, Coord, Rect};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// An unbounded grid that only stores the cells that have been set.
+///
+/// Coordinates can be negative, and the extent of the grid grows and shrinks
+/// as cells are set and removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, c: &Coord) -> Option<&T> {
+        self.cells.get(c)
+    }
+
+    pub fn get_mut(&mut self, c: &Coord) -> Option<&mut T> {
+        self.cells.get_mut(c)
+    }
+
+    /// Set the value at a coordinate, returning the previous value if there was one
+    pub fn set(&mut self, c: Coord, val: T) -> Option<T> {
+        self.cells.insert(c, val)
+    }
+
+    /// Remove the value at a coordinate, returning it if there was one
+    pub fn remove(&mut self, c: &Coord) -> Option<T> {
+        self.cells.remove(c)
+    }
+
+    pub fn contains(&self, c: &Coord) -> bool {
+        self.cells.contains_key(c)
+    }
+
+    /// Number of cells that have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterate over every set cell along with its coordinate, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(c, item)| (*c, item))
+    }
+
+    /// Iterate over the coordinates of every set cell, in no particular order
+    pub fn positions(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Get the smallest rectangle containing every set cell. Returns `None` if
+    /// the grid is empty.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        Rect::bounding_box(self.cells.keys())
+    }
+
+    /// Convert to a [`Board`] covering the bounding box, filling any cells that
+    /// aren't set with `fill`.
+    ///
+    /// The top left corner of the bounding box becomes `Coord(0, 0)` on the board.
+    /// An empty grid becomes an empty board.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Rect, SparseGrid};
+    ///
+    /// let mut grid = SparseGrid::new();
+    /// grid.set(Coord(-1, 3), '#');
+    /// grid.set(Coord(0, 5), '#');
+    ///
+    /// assert_eq!(grid.bounding_box(), Some(Rect::new(Coord(-1, 3), Coord(0, 5))));
+    /// assert_eq!(grid.to_board('.'), Board::from_str("#..\n..#"));
+    /// ```
+    pub fn to_board(&self, fill: T) -> Board<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounding_box() else {
+            return Board::from_size((0, 0), fill);
+        };
+
+        Board::from_fn(bounds.size(), |c| {
+            self.get(&(c + bounds.min())).unwrap_or(&fill).clone()
+        })
+    }
+
+    /// Convert a [`Board`] to a sparse grid, leaving out any cells equal to `fill`
+    pub fn from_board(board: &Board<T>, fill: &T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        board
+            .iter()
+            .filter(|(_, item)| *item != fill)
+            .map(|(c, item)| (c, item.clone()))
+            .collect()
+    }
+
+    /// Print the bounding box of the grid to the terminal, with `empty` in any
+    /// cells that aren't set
+    pub fn print(&self, empty: char)
+    where
+        T: Display,
+    {
+        let Some(bounds) = self.bounding_box() else {
+            return;
+        };
+        let (min, max) = (bounds.min(), bounds.max());
+
+        for row in min.0..=max.0 {
+            for col in min.1..=max.1 {
+                match self.get(&Coord(row, col)) {
+                    Some(item) => print!("{}", item),
+                    None => print!("{}", empty),
+                }
+            }
+            println!();
+        }
+    }
+
+    /// Print the bounding box of the grid with axes numbers, with `empty` in
+    /// any cells that aren't set
+    pub fn print_with_axes(&self, empty: char)
+    where
+        T: Display,
+    {
+        let Some(bounds) = self.bounding_box() else {
+            return;
+        };
+
+        let grid = grid_with_axes(bounds.min(), bounds.size(), |c| {
+            self.get(&c)
+                .map_or_else(|| empty.to_string(), |item| item.to_string())
+        });
+
+        print!("{}", grid);
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Coord, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+impl<T> From<HashMap<Coord, T>> for SparseGrid<T> {
+    fn from(cells: HashMap<Coord, T>) -> Self {
+        Self { cells }
+    }
+}
+
+impl<T> From<SparseGrid<T>> for HashMap<Coord, T> {
+    fn from(grid: SparseGrid<T>) -> Self {
+        grid.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set_remove() {
+        let mut grid: SparseGrid<u32> = SparseGrid::new();
+        assert_eq!(grid.bounding_box(), None);
+
+        assert_eq!(grid.set(Coord(-5, 2), 1), None);
+        assert_eq!(grid.set(Coord(3, -7), 2), None);
+        assert_eq!(grid.set(Coord(-5, 2), 3), Some(1));
+        *grid.get_mut(&Coord(3, -7)).unwrap() += 10;
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(&Coord(-5, 2)), Some(&3));
+        assert_eq!(grid.get(&Coord(3, -7)), Some(&12));
+        assert_eq!(
+            grid.bounding_box(),
+            Some(Rect::new(Coord(-5, -7), Coord(3, 2)))
+        );
+
+        // The bounding box shrinks back down
+        assert_eq!(grid.remove(&Coord(3, -7)), Some(12));
+        assert_eq!(grid.remove(&Coord(3, -7)), None);
+        assert_eq!(
+            grid.bounding_box(),
+            Some(Rect::new(Coord(-5, 2), Coord(-5, 2)))
+        );
+    }
+
+    #[test]
+    fn test_board_conversion() {
+        let board = Board::from_str(
+            "....\n\
+             .#..\n\
+             ...#",
+        );
+
+        let grid = SparseGrid::from_board(&board, &'.');
+        assert_eq!(grid.len(), 2);
+        assert!(grid.contains(&Coord(1, 1)));
+        assert!(grid.contains(&Coord(2, 3)));
+
+        // Only the bounding box makes it back to the board
+        assert_eq!(grid.to_board('.'), Board::from_str("#..\n..#"));
+        assert_eq!(SparseGrid::<char>::new().to_board('.').size(), (0, 0));
+    }
+}
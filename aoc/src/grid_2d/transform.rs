@@ -0,0 +1,206 @@
+use super::{Board, Coord, Rect};
+
+impl<T> Board<T>
+where
+    T: Clone,
+{
+    /// Construct a new board of the given size, where the element at each
+    /// position comes from the element at `source(position)` on this board.
+    /// The new board has the same topology.
+    fn remap<S, F>(&self, size: S, source: F) -> Self
+    where
+        S: Into<Coord>,
+        F: Fn(Coord) -> Coord,
+    {
+        Board::from_fn(size, |c| self[source(c)].clone()).with_topology(self.topology)
+    }
+
+    /// Swap the rows and columns of the board
+    pub fn transpose(&self) -> Self {
+        self.remap((self.width, self.height), |Coord(row, col)| Coord(col, row))
+    }
+
+    /// Rotate the board 90 degrees clockwise
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::Board;
+    ///
+    /// let board = Board::from_str(
+    ///     "ab\n\
+    ///      cd\n\
+    ///      ef",
+    /// );
+    ///
+    /// assert_eq!(board.rotate_cw(), Board::from_str("eca\nfdb"));
+    /// assert_eq!(board.rotate_ccw(), Board::from_str("bdf\nace"));
+    /// ```
+    pub fn rotate_cw(&self) -> Self {
+        let last_row = self.height as i32 - 1;
+
+        self.remap((self.width, self.height), |Coord(row, col)| {
+            Coord(last_row - col, row)
+        })
+    }
+
+    /// Rotate the board 90 degrees counter-clockwise
+    pub fn rotate_ccw(&self) -> Self {
+        let last_col = self.width as i32 - 1;
+
+        self.remap((self.width, self.height), |Coord(row, col)| {
+            Coord(col, last_col - row)
+        })
+    }
+
+    /// Rotate the board 180 degrees
+    pub fn rotate_180(&self) -> Self {
+        let (last_row, last_col) = (self.height as i32 - 1, self.width as i32 - 1);
+
+        self.remap(self.size(), |Coord(row, col)| {
+            Coord(last_row - row, last_col - col)
+        })
+    }
+
+    /// Mirror the board left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let last_col = self.width as i32 - 1;
+
+        self.remap(self.size(), |Coord(row, col)| Coord(row, last_col - col))
+    }
+
+    /// Mirror the board top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.height as i32 - 1;
+
+        self.remap(self.size(), |Coord(row, col)| Coord(last_row - row, col))
+    }
+
+    /// Get the part of the board within a rectangle.
+    ///
+    /// Any part of the rectangle that is off the board is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_2d::{Board, Coord, Rect};
+    ///
+    /// let board = Board::from_str(
+    ///     "abc\n\
+    ///      def\n\
+    ///      ghi",
+    /// );
+    ///
+    /// let bottom_right = Rect::new(Coord(1, 1), Coord(2, 2));
+    /// assert_eq!(board.crop(bottom_right), Board::from_str("ef\nhi"));
+    ///
+    /// let top_right = Rect::new(Coord(-5, 2), Coord(0, 9));
+    /// assert_eq!(board.crop(top_right), Board::from_str("c"));
+    /// ```
+    pub fn crop(&self, bounds: Rect) -> Self {
+        match self.bounds().and_then(|b| b.intersection(&bounds)) {
+            Some(bounds) => self.remap(bounds.size(), |c| c + bounds.min()),
+            None => self.remap((0, 0), |c| c),
+        }
+    }
+
+    /// Add a border `n` cells thick around every side of the board, filled
+    /// with `fill`
+    pub fn pad(&self, n: usize, fill: T) -> Self {
+        let offset = Coord(n as i32, n as i32);
+
+        Board::from_fn((self.height + 2 * n, self.width + 2 * n), |c| {
+            let c = c - offset;
+
+            if self.contains(&c) {
+                self[c].clone()
+            } else {
+                fill.clone()
+            }
+        })
+        .with_topology(self.topology)
+    }
+
+    /// Iterate over all 8 rotations and reflections of the board (the dihedral
+    /// symmetries), starting with the board itself.
+    ///
+    /// The first four are the board rotated clockwise 0, 90, 180 and 270 degrees,
+    /// and the last four are the same rotations of the board flipped horizontally.
+    pub fn symmetries(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..8).map(move |i| {
+            let board = if i < 4 {
+                self.clone()
+            } else {
+                self.flip_horizontal()
+            };
+
+            match i % 4 {
+                0 => board,
+                1 => board.rotate_cw(),
+                2 => board.rotate_180(),
+                _ => board.rotate_ccw(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn board() -> Board<char> {
+        Board::from_str(
+            "abc\n\
+             def",
+        )
+    }
+
+    #[test]
+    fn test_rotations() {
+        let board = board();
+
+        assert_eq!(board.rotate_cw(), Board::from_str("da\neb\nfc"));
+        assert_eq!(board.rotate_ccw(), Board::from_str("cf\nbe\nad"));
+        assert_eq!(board.rotate_180(), Board::from_str("fed\ncba"));
+        assert_eq!(board.rotate_cw().rotate_cw(), board.rotate_180());
+        assert_eq!(board.rotate_cw().rotate_ccw(), board);
+    }
+
+    #[test]
+    fn test_flips() {
+        let board = board();
+
+        assert_eq!(board.flip_horizontal(), Board::from_str("cba\nfed"));
+        assert_eq!(board.flip_vertical(), Board::from_str("def\nabc"));
+        assert_eq!(board.transpose(), Board::from_str("ad\nbe\ncf"));
+        assert_eq!(board.transpose().transpose(), board);
+    }
+
+    #[test]
+    fn test_crop_and_pad() {
+        let board = board();
+        let padded = board.pad(1, '.');
+
+        assert_eq!(padded, Board::from_str(".....\n.abc.\n.def.\n....."));
+        assert_eq!(padded.crop(Rect::new(Coord(1, 1), Coord(2, 3))), board);
+        assert_eq!(
+            board.crop(Rect::new(Coord(5, 5), Coord(6, 6))).size(),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let board = board();
+        let symmetries: Vec<Board<char>> = board.symmetries().collect();
+
+        assert_eq!(symmetries.len(), 8);
+        assert_eq!(symmetries[0], board);
+        assert_eq!(symmetries[4], board.flip_horizontal());
+
+        // All distinct, since the board has no symmetry of its own
+        let distinct: HashSet<Board<char>> = symmetries.into_iter().collect();
+        assert_eq!(distinct.len(), 8);
+        assert!(distinct.contains(&board.transpose()));
+        assert!(distinct.contains(&board.flip_vertical()));
+    }
+}
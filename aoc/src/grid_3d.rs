@@ -0,0 +1,678 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+/// An (x, y, z) coordinate triple or vector. Using i32 so that we can subtract
+/// or have negative vectors.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub struct Coord3(pub i32, pub i32, pub i32);
+
+impl Coord3 {
+    /// Get the 6 neighbours of a coordinate that share a face with it
+    pub fn face_neighbours(&self) -> [Coord3; 6] {
+        [
+            Coord3(self.0 - 1, self.1, self.2),
+            Coord3(self.0 + 1, self.1, self.2),
+            Coord3(self.0, self.1 - 1, self.2),
+            Coord3(self.0, self.1 + 1, self.2),
+            Coord3(self.0, self.1, self.2 - 1),
+            Coord3(self.0, self.1, self.2 + 1),
+        ]
+    }
+
+    /// Compute the manhattan distance between two coordinates
+    pub fn manhattan_distance(&self, other: &Coord3) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1) + self.2.abs_diff(other.2)
+    }
+
+    /// Compute the L1-norm of the coordinate vector
+    ///
+    /// The L1-norm is the sum of the absolute values of the components.
+    pub fn l1_norm(&self) -> u32 {
+        self.0.unsigned_abs() + self.1.unsigned_abs() + self.2.unsigned_abs()
+    }
+
+    /// Component-wise minimum of two coordinates
+    pub fn min(&self, other: &Coord3) -> Coord3 {
+        Coord3(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+        )
+    }
+
+    /// Component-wise maximum of two coordinates
+    pub fn max(&self, other: &Coord3) -> Coord3 {
+        Coord3(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+        )
+    }
+}
+
+impl From<Coord3> for (i32, i32, i32) {
+    fn from(value: Coord3) -> Self {
+        (value.0, value.1, value.2)
+    }
+}
+
+impl From<(i32, i32, i32)> for Coord3 {
+    fn from(value: (i32, i32, i32)) -> Self {
+        Coord3(value.0, value.1, value.2)
+    }
+}
+
+impl From<(usize, usize, usize)> for Coord3 {
+    fn from(value: (usize, usize, usize)) -> Self {
+        Coord3(value.0 as i32, value.1 as i32, value.2 as i32)
+    }
+}
+
+impl Add<Coord3> for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: Coord3) -> Self::Output {
+        Coord3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Add<&Coord3> for &Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: &Coord3) -> Self::Output {
+        Coord3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub<Coord3> for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: Coord3) -> Self::Output {
+        Coord3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Sub<&Coord3> for &Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: &Coord3) -> Self::Output {
+        Coord3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Mul<i32> for Coord3 {
+    type Output = Coord3;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Coord3(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl Mul<i32> for &Coord3 {
+    type Output = Coord3;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Coord3(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+/// Which neighbouring cells count as connected to a cell
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum Connectivity {
+    /// Only cells sharing a face are connected
+    Six,
+    /// Cells sharing a face, edge or corner are connected
+    TwentySix,
+}
+
+/// Offsets to the cells sharing a face
+const FACE_OFFSETS: [Coord3; 6] = [
+    Coord3(-1, 0, 0),
+    Coord3(1, 0, 0),
+    Coord3(0, -1, 0),
+    Coord3(0, 1, 0),
+    Coord3(0, 0, -1),
+    Coord3(0, 0, 1),
+];
+
+/// Offsets to the cells sharing a face, edge or corner
+const ALL_OFFSETS: [Coord3; 26] = {
+    let mut offsets = [Coord3(0, 0, 0); 26];
+    let mut i = 0;
+    let mut n = 0;
+
+    // Every offset in the 3x3x3 cube around the cell, skipping the cell itself
+    while i < 27 {
+        if i != 13 {
+            offsets[n] = Coord3(i / 9 - 1, i / 3 % 3 - 1, i % 3 - 1);
+            n += 1;
+        }
+        i += 1;
+    }
+
+    offsets
+};
+
+impl Connectivity {
+    /// The offsets from a cell to each of its connected neighbours
+    pub fn offsets(self) -> &'static [Coord3] {
+        match self {
+            Connectivity::Six => &FACE_OFFSETS,
+            Connectivity::TwentySix => &ALL_OFFSETS,
+        }
+    }
+
+    /// Get the connected neighbours of a coordinate
+    pub fn neighbours(self, c: &Coord3) -> impl Iterator<Item = Coord3> {
+        let c = *c;
+
+        self.offsets().iter().map(move |&offset| c + offset)
+    }
+}
+
+/// Get the (min, max) corners of the smallest box containing every coordinate,
+/// both inclusive. Returns `None` if there are no coordinates.
+pub fn bounding_box<'a, I>(coords: I) -> Option<(Coord3, Coord3)>
+where
+    I: IntoIterator<Item = &'a Coord3>,
+{
+    let mut coords = coords.into_iter();
+    let first = *coords.next()?;
+
+    Some(coords.fold((first, first), |(min, max), c| (min.min(c), max.max(c))))
+}
+
+/// Count the faces of solid cells that touch a cell that isn't solid
+fn count_surface_area<I, F>(solid_cells: I, is_solid: F) -> usize
+where
+    I: IntoIterator<Item = Coord3>,
+    F: Fn(&Coord3) -> bool,
+{
+    solid_cells
+        .into_iter()
+        .map(|c| c.face_neighbours().iter().filter(|n| !is_solid(n)).count())
+        .sum()
+}
+
+/// Flood fill the empty cells reachable from outside of the (min, max) box,
+/// moving only between cells that share a face. Searches a box one cell larger
+/// than the given one on every side, so that the fill can get all the way around.
+fn fill_exterior<F>(min: Coord3, max: Coord3, is_solid: F) -> HashSet<Coord3>
+where
+    F: Fn(&Coord3) -> bool,
+{
+    let min = min - Coord3(1, 1, 1);
+    let max = max + Coord3(1, 1, 1);
+    let in_bounds = |c: &Coord3| *c == c.max(&min).min(&max);
+
+    let mut exterior: HashSet<Coord3> = HashSet::from([min]);
+    let mut queue: Vec<Coord3> = vec![min];
+
+    while let Some(position) = queue.pop() {
+        for neighbour in position.face_neighbours() {
+            if in_bounds(&neighbour) && !is_solid(&neighbour) && exterior.insert(neighbour) {
+                queue.push(neighbour);
+            }
+        }
+    }
+
+    exterior
+}
+
+/// A dense 3D grid of elements, indexed by (x, y, z) [`Coord3`]s.
+///
+/// Elements are stored contiguously, with x varying fastest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board3<T> {
+    size: (usize, usize, usize),
+    cells: Vec<T>,
+}
+
+impl<T> Board3<T> {
+    /// Construct a board with the given (x, y, z) size, and fill all elements
+    /// with the given item
+    pub fn from_size<S>(size: S, item: T) -> Self
+    where
+        S: Into<Coord3>,
+        T: Clone,
+    {
+        Self::from_fn(size, |_| item.clone())
+    }
+
+    /// Construct a board with the given (x, y, z) size, where each element is
+    /// the result of calling `f` with its coordinate
+    pub fn from_fn<S, F>(size: S, mut f: F) -> Self
+    where
+        S: Into<Coord3>,
+        F: FnMut(Coord3) -> T,
+    {
+        let size = size.into();
+        let size = (
+            size.0.max(0) as usize,
+            size.1.max(0) as usize,
+            size.2.max(0) as usize,
+        );
+
+        let mut cells = Vec::with_capacity(size.0 * size.1 * size.2);
+        for z in 0..size.2 {
+            for y in 0..size.1 {
+                for x in 0..size.0 {
+                    cells.push(f((x, y, z).into()));
+                }
+            }
+        }
+
+        Self { size, cells }
+    }
+
+    /// Get the (x, y, z) size of the board
+    pub fn size(&self) -> (usize, usize, usize) {
+        self.size
+    }
+
+    /// Whether the coordinate lies on the board
+    pub fn contains(&self, c: &Coord3) -> bool {
+        let (sx, sy, sz) = self.size;
+
+        c.0 >= 0
+            && (c.0 as usize) < sx
+            && c.1 >= 0
+            && (c.1 as usize) < sy
+            && c.2 >= 0
+            && (c.2 as usize) < sz
+    }
+
+    /// Index into `cells` for a coordinate, if it's on the board
+    fn index_of(&self, c: &Coord3) -> Option<usize> {
+        let (sx, sy, _) = self.size;
+
+        self.contains(c)
+            .then(|| (c.2 as usize * sy + c.1 as usize) * sx + c.0 as usize)
+    }
+
+    /// Coordinate of an index into `cells`
+    fn coord_of(&self, index: usize) -> Coord3 {
+        let (sx, sy, _) = self.size;
+
+        (index % sx, (index / sx) % sy, index / (sx * sy)).into()
+    }
+
+    pub fn get(&self, c: &Coord3) -> Option<&T> {
+        self.index_of(c).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, c: &Coord3) -> Option<&mut T> {
+        self.index_of(c).map(|i| &mut self.cells[i])
+    }
+
+    /// Set the value at a coordinate
+    ///
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    pub fn set(&mut self, c: &Coord3, val: T) {
+        self[*c] = val;
+    }
+
+    /// Iterate over every element of the board along with its coordinate
+    pub fn iter(&self) -> impl Iterator<Item = (Coord3, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (self.coord_of(i), item))
+    }
+
+    /// Construct a vector of all coordinate positions on the board
+    pub fn positions(&self) -> Vec<Coord3> {
+        (0..self.cells.len()).map(|i| self.coord_of(i)).collect()
+    }
+
+    /// Find the position of all occurrences of `elem` on the board.
+    pub fn find(&self, elem: &T) -> Vec<Coord3>
+    where
+        T: Eq,
+    {
+        self.iter()
+            .filter(|(_, e)| *e == elem)
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Get the (min, max) corners of the board, both inclusive. Returns `None`
+    /// if the board is empty.
+    pub fn bounding_box(&self) -> Option<(Coord3, Coord3)> {
+        let (sx, sy, sz) = self.size;
+
+        (!self.cells.is_empty()).then(|| {
+            (
+                Coord3(0, 0, 0),
+                Coord3(sx as i32 - 1, sy as i32 - 1, sz as i32 - 1),
+            )
+        })
+    }
+
+    /// Get the neighbours of a coordinate that are on the board, along with
+    /// their elements
+    pub fn neighbours(
+        &self,
+        c: &Coord3,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        connectivity
+            .neighbours(c)
+            .filter_map(|n| self.get(&n).map(|item| (n, item)))
+    }
+
+    /// Count the faces of cells matching `is_solid` that don't touch another
+    /// solid cell, including faces on the edge of the board
+    pub fn surface_area<P>(&self, is_solid: P) -> usize
+    where
+        P: Fn(&T) -> bool,
+    {
+        let solid = |c: &Coord3| self.get(c).is_some_and(&is_solid);
+
+        count_surface_area(self.positions().into_iter().filter(solid), solid)
+    }
+
+    /// Flood fill from outside of the board to find every cell that isn't
+    /// solid and isn't enclosed by solid cells
+    pub fn exterior<P>(&self, is_solid: P) -> HashSet<Coord3>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let Some((min, max)) = self.bounding_box() else {
+            return HashSet::new();
+        };
+
+        let solid = |c: &Coord3| self.get(c).is_some_and(&is_solid);
+
+        fill_exterior(min, max, solid)
+            .into_iter()
+            .filter(|c| self.contains(c))
+            .collect()
+    }
+
+    /// Like [`Board3::surface_area`], but only counts faces on the outside of
+    /// the solid cells, not faces of pockets enclosed by them
+    pub fn exterior_surface_area<P>(&self, is_solid: P) -> usize
+    where
+        P: Fn(&T) -> bool,
+    {
+        let Some((min, max)) = self.bounding_box() else {
+            return 0;
+        };
+
+        let solid = |c: &Coord3| self.get(c).is_some_and(&is_solid);
+        let exterior = fill_exterior(min, max, solid);
+
+        count_surface_area(self.positions().into_iter().filter(solid), |c| {
+            !exterior.contains(c)
+        })
+    }
+}
+
+impl<T> Index<Coord3> for Board3<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    fn index(&self, c: Coord3) -> &Self::Output {
+        let i = self
+            .index_of(&c)
+            .unwrap_or_else(|| panic!("Coordinate {:?} is outside of the board", c));
+
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Coord3> for Board3<T> {
+    /// # Panics
+    /// Panics if the coordinate is outside of the board
+    fn index_mut(&mut self, c: Coord3) -> &mut Self::Output {
+        let i = self
+            .index_of(&c)
+            .unwrap_or_else(|| panic!("Coordinate {:?} is outside of the board", c));
+
+        &mut self.cells[i]
+    }
+}
+
+/// An unbounded 3D grid that only stores the cells that have been set.
+///
+/// Useful for sets of voxels, where the set cells are the solid ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid3<T> {
+    cells: HashMap<Coord3, T>,
+}
+
+impl<T> Default for SparseGrid3<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid3<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, c: &Coord3) -> Option<&T> {
+        self.cells.get(c)
+    }
+
+    pub fn get_mut(&mut self, c: &Coord3) -> Option<&mut T> {
+        self.cells.get_mut(c)
+    }
+
+    /// Set the value at a coordinate, returning the previous value if there was one
+    pub fn set(&mut self, c: Coord3, val: T) -> Option<T> {
+        self.cells.insert(c, val)
+    }
+
+    /// Remove the value at a coordinate, returning it if there was one
+    pub fn remove(&mut self, c: &Coord3) -> Option<T> {
+        self.cells.remove(c)
+    }
+
+    pub fn contains(&self, c: &Coord3) -> bool {
+        self.cells.contains_key(c)
+    }
+
+    /// Number of cells that have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterate over every set cell along with its coordinate, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord3, &T)> {
+        self.cells.iter().map(|(c, item)| (*c, item))
+    }
+
+    /// Iterate over the coordinates of every set cell, in no particular order
+    pub fn positions(&self) -> impl Iterator<Item = Coord3> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Get the (min, max) corners of the smallest box containing every set
+    /// cell, both inclusive. Returns `None` if the grid is empty.
+    pub fn bounding_box(&self) -> Option<(Coord3, Coord3)> {
+        bounding_box(self.cells.keys())
+    }
+
+    /// Get the set neighbours of a coordinate, along with their elements
+    pub fn neighbours(
+        &self,
+        c: &Coord3,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        connectivity
+            .neighbours(c)
+            .filter_map(|n| self.get(&n).map(|item| (n, item)))
+    }
+
+    /// Count the faces of set cells that don't touch another set cell
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::grid_3d::{Coord3, SparseGrid3};
+    ///
+    /// let cubes: SparseGrid3<()> = [Coord3(1, 1, 1), Coord3(2, 1, 1)]
+    ///     .into_iter()
+    ///     .map(|c| (c, ()))
+    ///     .collect();
+    ///
+    /// assert_eq!(cubes.surface_area(), 10);
+    /// ```
+    pub fn surface_area(&self) -> usize {
+        count_surface_area(self.positions(), |c| self.contains(c))
+    }
+
+    /// Flood fill from outside of the bounding box to find every cell that
+    /// isn't set and isn't enclosed by set cells.
+    ///
+    /// Includes a layer of cells one outside the bounding box, so that the
+    /// exterior is connected.
+    pub fn exterior(&self) -> HashSet<Coord3> {
+        let Some((min, max)) = self.bounding_box() else {
+            return HashSet::new();
+        };
+
+        fill_exterior(min, max, |c| self.contains(c))
+    }
+
+    /// Like [`SparseGrid3::surface_area`], but only counts faces on the outside
+    /// of the set cells, not faces of pockets enclosed by them
+    pub fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+
+        count_surface_area(self.positions(), |c| !exterior.contains(c))
+    }
+}
+
+impl<T> FromIterator<(Coord3, T)> for SparseGrid3<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord3, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Coord3, T)> for SparseGrid3<T> {
+    fn extend<I: IntoIterator<Item = (Coord3, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Example droplet from 2022 day 18
+    fn droplet() -> SparseGrid3<()> {
+        [
+            (2, 2, 2),
+            (1, 2, 2),
+            (3, 2, 2),
+            (2, 1, 2),
+            (2, 3, 2),
+            (2, 2, 1),
+            (2, 2, 3),
+            (2, 2, 4),
+            (2, 2, 6),
+            (1, 2, 5),
+            (3, 2, 5),
+            (2, 1, 5),
+            (2, 3, 5),
+        ]
+        .into_iter()
+        .map(|c: (i32, i32, i32)| (c.into(), ()))
+        .collect()
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        assert_eq!(Connectivity::Six.offsets().len(), 6);
+        assert_eq!(Connectivity::TwentySix.offsets().len(), 26);
+        let all: HashSet<Coord3> = Connectivity::TwentySix.offsets().iter().copied().collect();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&Coord3(0, 0, 0)));
+        assert!(Connectivity::Six.offsets().iter().all(|o| all.contains(o)));
+
+        let c = Coord3(1, -2, 3);
+        let face: HashSet<Coord3> = c.face_neighbours().into_iter().collect();
+        assert_eq!(face.len(), 6);
+        assert!(face.iter().all(|n| n.manhattan_distance(&c) == 1));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let droplet = droplet();
+
+        assert_eq!(
+            droplet.bounding_box(),
+            Some((Coord3(1, 1, 1), Coord3(3, 3, 6)))
+        );
+        assert_eq!(SparseGrid3::<()>::new().bounding_box(), None);
+    }
+
+    #[test]
+    fn test_sparse_surface_area() {
+        let droplet = droplet();
+
+        assert_eq!(droplet.surface_area(), 64);
+        assert_eq!(droplet.exterior_surface_area(), 58);
+
+        // The single air pocket isn't reachable from outside
+        assert!(!droplet.exterior().contains(&Coord3(2, 2, 5)));
+        assert!(droplet.exterior().contains(&Coord3(0, 0, 0)));
+    }
+
+    #[test]
+    fn test_board_surface_area() {
+        // A hollow 3x3x3 cube
+        let board = Board3::from_fn((3, 3, 3), |c| c != Coord3(1, 1, 1));
+
+        assert_eq!(board.surface_area(|solid| *solid), 54 + 6);
+        assert_eq!(board.exterior_surface_area(|solid| *solid), 54);
+        assert_eq!(board.exterior(|solid| *solid).len(), 0);
+
+        // With a hole punched through the middle of one face, the inside is
+        // reachable, but it's only counted as part of the exterior if it's on the board
+        let mut board = board;
+        board.set(&Coord3(1, 1, 0), false);
+        assert_eq!(board.exterior(|solid| *solid).len(), 2);
+        assert_eq!(
+            board.surface_area(|solid| *solid),
+            board.exterior_surface_area(|solid| *solid)
+        );
+    }
+
+    #[test]
+    fn test_board_indexing() {
+        let mut board = Board3::from_size((2, 3, 4), 0);
+        assert_eq!(board.size(), (2, 3, 4));
+        assert_eq!(board.positions().len(), 24);
+
+        board[Coord3(1, 2, 3)] = 5;
+        assert_eq!(board.get(&Coord3(1, 2, 3)), Some(&5));
+        assert_eq!(board.get(&Coord3(2, 2, 3)), None);
+        assert_eq!(board.find(&5), vec![Coord3(1, 2, 3)]);
+
+        // Every position maps back to itself
+        for (i, c) in board.positions().into_iter().enumerate() {
+            assert_eq!(board.index_of(&c), Some(i));
+        }
+
+        assert_eq!(
+            board
+                .neighbours(&Coord3(0, 0, 0), Connectivity::TwentySix)
+                .count(),
+            7
+        );
+    }
+}
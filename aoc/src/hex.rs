@@ -0,0 +1,635 @@
+//! Hexagonal grids, using axial coordinates.
+//!
+//! Hexes are "pointy-topped", so each hex has neighbours to the east and west,
+//! and the other four on the diagonals. Axial coordinates are `(q, r)`, where
+//! `q` increases to the east and `r` increases to the south-east. The third
+//! cube coordinate is `s = -q - r`.
+//!
+//! Puzzles on "flat-topped" grids, with neighbours to the north and south
+//! instead, can use [`FlatHexDir`]. That's the same grid turned 30 degrees, so
+//! everything else works the same.
+//!
+//! See <https://www.redblobgames.com/grids/hexagons/> for a great reference.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+/// A (q, r) axial hex coordinate or vector
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Default)]
+pub struct Hex(pub i32, pub i32);
+
+impl Hex {
+    /// Construct a hex from cube coordinates
+    ///
+    /// # Panics
+    /// Panics if the coordinates don't sum to zero
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates must sum to zero");
+
+        Hex(q, r)
+    }
+
+    /// The third cube coordinate
+    pub fn s(&self) -> i32 {
+        -self.0 - self.1
+    }
+
+    /// Convert to (q, r, s) cube coordinates
+    pub fn to_cube(self) -> (i32, i32, i32) {
+        (self.0, self.1, self.s())
+    }
+
+    /// Get the neighbours of a hex, sorted clockwise starting from the east
+    pub fn neighbours(&self) -> [Hex; 6] {
+        HexDir::all().map(|dir| self + dir)
+    }
+
+    /// Compute the number of steps between two hexes
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// assert_eq!(Hex(0, 0).distance(&Hex(3, -1)), 3);
+    /// assert_eq!(Hex(1, 1).distance(&Hex(-1, 0)), 3);
+    /// ```
+    pub fn distance(&self, other: &Hex) -> u32 {
+        (*self - *other).length()
+    }
+
+    /// Compute the number of steps from the origin to this hex
+    pub fn length(&self) -> u32 {
+        (self.0.unsigned_abs() + self.1.unsigned_abs() + self.s().unsigned_abs()) / 2
+    }
+
+    /// Rotate the vector 60 degrees clockwise about the origin
+    pub fn rotate_right(self) -> Self {
+        let (q, r, s) = self.to_cube();
+
+        Hex::from_cube(-r, -s, -q)
+    }
+
+    /// Rotate the vector 60 degrees counter-clockwise about the origin
+    pub fn rotate_left(self) -> Self {
+        let (q, r, s) = self.to_cube();
+
+        Hex::from_cube(-s, -q, -r)
+    }
+
+    /// Get every hex exactly `radius` steps away, going around the ring
+    /// counter-clockwise starting from the south-west.
+    ///
+    /// A radius of 0 is just the hex itself.
+    pub fn ring(&self, radius: u32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        let radius = radius as i32;
+        let mut hex = self + &(Hex::from(HexDir::SouthWest) * radius);
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+
+        // Walk each edge of the ring in turn
+        for dir in [
+            HexDir::East,
+            HexDir::NorthEast,
+            HexDir::NorthWest,
+            HexDir::West,
+            HexDir::SouthWest,
+            HexDir::SouthEast,
+        ] {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + dir;
+            }
+        }
+
+        ring
+    }
+
+    /// Get every hex up to `radius` steps away, starting with this hex and
+    /// then working outwards one ring at a time
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::hex::Hex;
+    ///
+    /// let spiral = Hex(0, 0).spiral(2);
+    ///
+    /// assert_eq!(spiral.len(), 1 + 6 + 12);
+    /// assert_eq!(spiral[0], Hex(0, 0));
+    /// assert!(spiral.iter().all(|h| h.length() <= 2));
+    /// ```
+    pub fn spiral(&self, radius: u32) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+}
+
+impl From<Hex> for (i32, i32) {
+    fn from(value: Hex) -> Self {
+        (value.0, value.1)
+    }
+}
+
+impl From<(i32, i32)> for Hex {
+    fn from(value: (i32, i32)) -> Self {
+        Hex(value.0, value.1)
+    }
+}
+
+impl Add<Hex> for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Self::Output {
+        Hex(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Add<&Hex> for &Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: &Hex) -> Self::Output {
+        Hex(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub<Hex> for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Self::Output {
+        Hex(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Sub<&Hex> for &Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: &Hex) -> Self::Output {
+        Hex(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Hex(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Mul<i32> for &Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Hex(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+/// The six directions to neighbouring hexes
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
+pub enum HexDir {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDir {
+    /// Rotate the direction 60 degrees clockwise
+    pub fn rotate_right(self) -> Self {
+        match self {
+            HexDir::East => HexDir::SouthEast,
+            HexDir::SouthEast => HexDir::SouthWest,
+            HexDir::SouthWest => HexDir::West,
+            HexDir::West => HexDir::NorthWest,
+            HexDir::NorthWest => HexDir::NorthEast,
+            HexDir::NorthEast => HexDir::East,
+        }
+    }
+
+    /// Rotate the direction 60 degrees counter-clockwise
+    pub fn rotate_left(self) -> Self {
+        match self {
+            HexDir::East => HexDir::NorthEast,
+            HexDir::SouthEast => HexDir::East,
+            HexDir::SouthWest => HexDir::SouthEast,
+            HexDir::West => HexDir::SouthWest,
+            HexDir::NorthWest => HexDir::West,
+            HexDir::NorthEast => HexDir::NorthWest,
+        }
+    }
+
+    /// Rotate the direction 180 degrees
+    pub fn rotate_180(self) -> Self {
+        match self {
+            HexDir::East => HexDir::West,
+            HexDir::SouthEast => HexDir::NorthWest,
+            HexDir::SouthWest => HexDir::NorthEast,
+            HexDir::West => HexDir::East,
+            HexDir::NorthWest => HexDir::SouthEast,
+            HexDir::NorthEast => HexDir::SouthWest,
+        }
+    }
+
+    /// Convert the direction to degrees clockwise from north, between 0 and 359
+    pub fn to_degrees(self) -> u32 {
+        match self {
+            HexDir::NorthEast => 30,
+            HexDir::East => 90,
+            HexDir::SouthEast => 150,
+            HexDir::SouthWest => 210,
+            HexDir::West => 270,
+            HexDir::NorthWest => 330,
+        }
+    }
+
+    /// All directions, clockwise starting from the east
+    pub const fn all() -> [Self; 6] {
+        [
+            HexDir::East,
+            HexDir::SouthEast,
+            HexDir::SouthWest,
+            HexDir::West,
+            HexDir::NorthWest,
+            HexDir::NorthEast,
+        ]
+    }
+
+    /// Parse a sequence of directions, such as `"ne,ne,sw"` or `"esenee"`.
+    ///
+    /// Directions can be separated by commas or whitespace, or not separated
+    /// at all. A separator can't split up a direction. Paths on a flat-topped
+    /// grid, going north and south, are read with [`FlatHexDir::parse_path`].
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::hex::{Hex, HexDir};
+    ///
+    /// let path = HexDir::parse_path("esew").unwrap();
+    /// assert_eq!(path, vec![HexDir::East, HexDir::SouthEast, HexDir::West]);
+    ///
+    /// let end = path.into_iter().fold(Hex(0, 0), |hex, dir| hex + dir);
+    /// assert_eq!(end, Hex(0, 0) + HexDir::SouthEast);
+    ///
+    /// assert!(HexDir::parse_path("nw, se").is_ok());
+    /// assert!(HexDir::parse_path("ns").is_err());
+    /// ```
+    pub fn parse_path(s: &str) -> Result<Vec<HexDir>, ParseHexDirError> {
+        let mut path = Vec::new();
+        let mut chars = s
+            .char_indices()
+            .filter(|(_, c)| *c != ',' && !c.is_whitespace())
+            .peekable();
+
+        while let Some((start, c)) = chars.next() {
+            // North and south always need a second character for east or west
+            let end = match c {
+                'n' | 's' => {
+                    let (i, c) = chars
+                        .next()
+                        .ok_or_else(|| ParseHexDirError(c.to_string()))?;
+                    i + c.len_utf8()
+                }
+                _ => start + c.len_utf8(),
+            };
+
+            path.push(s[start..end].parse()?);
+        }
+
+        Ok(path)
+    }
+}
+
+/// Error from parsing an unrecognized hex direction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHexDirError(pub String);
+
+impl Display for ParseHexDirError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid hex direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseHexDirError {}
+
+impl FromStr for HexDir {
+    type Err = ParseHexDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(HexDir::East),
+            "se" => Ok(HexDir::SouthEast),
+            "sw" => Ok(HexDir::SouthWest),
+            "w" => Ok(HexDir::West),
+            "nw" => Ok(HexDir::NorthWest),
+            "ne" => Ok(HexDir::NorthEast),
+            _ => Err(ParseHexDirError(s.to_string())),
+        }
+    }
+}
+
+impl Display for HexDir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            HexDir::East => "e",
+            HexDir::SouthEast => "se",
+            HexDir::SouthWest => "sw",
+            HexDir::West => "w",
+            HexDir::NorthWest => "nw",
+            HexDir::NorthEast => "ne",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Convert a direction to a unit hex vector
+impl From<HexDir> for Hex {
+    fn from(dir: HexDir) -> Self {
+        match dir {
+            HexDir::East => Hex(1, 0),
+            HexDir::SouthEast => Hex(0, 1),
+            HexDir::SouthWest => Hex(-1, 1),
+            HexDir::West => Hex(-1, 0),
+            HexDir::NorthWest => Hex(0, -1),
+            HexDir::NorthEast => Hex(1, -1),
+        }
+    }
+}
+
+impl Add<HexDir> for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: HexDir) -> Self::Output {
+        self + Hex::from(rhs)
+    }
+}
+
+impl Add<HexDir> for &Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: HexDir) -> Self::Output {
+        *self + Hex::from(rhs)
+    }
+}
+
+/// The six directions to neighbouring hexes on a flat-topped grid.
+///
+/// Each direction is 30 degrees counter-clockwise from the matching
+/// [`HexDir`], so that north is the same vector as [`HexDir::NorthEast`].
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
+pub enum FlatHexDir {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FlatHexDir {
+    /// All directions, clockwise starting from the north
+    pub const fn all() -> [Self; 6] {
+        [
+            FlatHexDir::North,
+            FlatHexDir::NorthEast,
+            FlatHexDir::SouthEast,
+            FlatHexDir::South,
+            FlatHexDir::SouthWest,
+            FlatHexDir::NorthWest,
+        ]
+    }
+
+    /// Parse a sequence of directions, such as `"n,ne,s"` or `"nsesw"`.
+    ///
+    /// Directions can be separated by commas or whitespace, or not separated
+    /// at all. A separator can't split up a direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::hex::{FlatHexDir, Hex};
+    ///
+    /// let path = FlatHexDir::parse_path("ne,ne,s,s").unwrap();
+    /// let end = path.into_iter().fold(Hex(0, 0), |hex, dir| hex + dir);
+    /// assert_eq!(end.length(), 2);
+    ///
+    /// assert!(FlatHexDir::parse_path("e").is_err());
+    /// ```
+    pub fn parse_path(s: &str) -> Result<Vec<FlatHexDir>, ParseHexDirError> {
+        let mut path = Vec::new();
+        let mut chars = s
+            .char_indices()
+            .filter(|(_, c)| *c != ',' && !c.is_whitespace())
+            .peekable();
+
+        while let Some((start, c)) = chars.next() {
+            let mut end = start + c.len_utf8();
+
+            // North and south can be followed straight away by east or west
+            if matches!(c, 'n' | 's') {
+                if let Some(&(i, next @ ('e' | 'w'))) = chars.peek() {
+                    if i == end {
+                        end += next.len_utf8();
+                        chars.next();
+                    }
+                }
+            }
+
+            path.push(s[start..end].parse()?);
+        }
+
+        Ok(path)
+    }
+}
+
+impl FromStr for FlatHexDir {
+    type Err = ParseHexDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(FlatHexDir::North),
+            "ne" => Ok(FlatHexDir::NorthEast),
+            "se" => Ok(FlatHexDir::SouthEast),
+            "s" => Ok(FlatHexDir::South),
+            "sw" => Ok(FlatHexDir::SouthWest),
+            "nw" => Ok(FlatHexDir::NorthWest),
+            _ => Err(ParseHexDirError(s.to_string())),
+        }
+    }
+}
+
+impl Display for FlatHexDir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            FlatHexDir::North => "n",
+            FlatHexDir::NorthEast => "ne",
+            FlatHexDir::SouthEast => "se",
+            FlatHexDir::South => "s",
+            FlatHexDir::SouthWest => "sw",
+            FlatHexDir::NorthWest => "nw",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Convert a flat-topped direction to a unit hex vector
+impl From<FlatHexDir> for Hex {
+    fn from(dir: FlatHexDir) -> Self {
+        match dir {
+            FlatHexDir::North => Hex(1, -1),
+            FlatHexDir::NorthEast => Hex(1, 0),
+            FlatHexDir::SouthEast => Hex(0, 1),
+            FlatHexDir::South => Hex(-1, 1),
+            FlatHexDir::SouthWest => Hex(-1, 0),
+            FlatHexDir::NorthWest => Hex(0, -1),
+        }
+    }
+}
+
+impl Add<FlatHexDir> for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: FlatHexDir) -> Self::Output {
+        self + Hex::from(rhs)
+    }
+}
+
+impl Add<FlatHexDir> for &Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: FlatHexDir) -> Self::Output {
+        *self + Hex::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_directions() {
+        for dir in HexDir::all() {
+            assert_eq!(dir.rotate_right().rotate_left(), dir);
+            assert_eq!(
+                dir.rotate_180(),
+                dir.rotate_right().rotate_right().rotate_right()
+            );
+            assert_eq!(Hex(0, 0) + dir + dir.rotate_180(), Hex(0, 0));
+
+            // Rotating the direction and rotating its vector agree
+            assert_eq!(Hex::from(dir.rotate_right()), Hex::from(dir).rotate_right());
+            assert_eq!(Hex::from(dir.rotate_left()), Hex::from(dir).rotate_left());
+
+            assert_eq!(dir.to_string().parse::<HexDir>(), Ok(dir));
+        }
+    }
+
+    #[test]
+    fn test_cube_coordinates() {
+        let hex = Hex::from_cube(2, -3, 1);
+
+        assert_eq!(hex, Hex(2, -3));
+        assert_eq!(hex.s(), 1);
+        assert_eq!(hex.to_cube(), (2, -3, 1));
+        assert_eq!(hex.length(), 3);
+    }
+
+    #[test]
+    fn test_rings() {
+        let center = Hex(3, -2);
+
+        for radius in 1..5 {
+            let ring = center.ring(radius);
+            let distinct: HashSet<Hex> = ring.iter().copied().collect();
+
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert_eq!(distinct.len(), ring.len());
+            assert!(ring.iter().all(|h| h.distance(&center) == radius));
+
+            // Consecutive hexes in the ring are neighbours
+            for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+                assert_eq!(a.distance(b), 1);
+            }
+        }
+
+        assert_eq!(center.ring(0), vec![center]);
+    }
+
+    #[test]
+    fn test_parse_path() {
+        // Example from 2020 day 24, which ends up back at the start
+        let path = HexDir::parse_path("nwwswee").unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.into_iter().fold(Hex(0, 0), |h, d| h + d), Hex(0, 0));
+
+        let path = HexDir::parse_path("ne,ne,sw,sw").unwrap();
+        assert_eq!(path.into_iter().fold(Hex(0, 0), |h, d| h + d), Hex(0, 0));
+
+        assert_eq!(
+            HexDir::parse_path("ex"),
+            Err(ParseHexDirError("x".to_string()))
+        );
+        assert_eq!(
+            HexDir::parse_path("ees"),
+            Err(ParseHexDirError("s".to_string()))
+        );
+
+        // Separators between the two letters of a direction
+        assert_eq!(
+            HexDir::parse_path("n,e"),
+            Err(ParseHexDirError("n,e".to_string()))
+        );
+        assert_eq!(
+            HexDir::parse_path("se s w"),
+            Err(ParseHexDirError("s w".to_string()))
+        );
+        assert_eq!(
+            HexDir::parse_path("n é"),
+            Err(ParseHexDirError("n é".to_string()))
+        );
+        assert_eq!(
+            HexDir::parse_path("ne, s"),
+            Err(ParseHexDirError("s".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_flat_directions() {
+        let dirs = FlatHexDir::all();
+        assert_eq!(Hex::from(FlatHexDir::North), Hex::from(HexDir::NorthEast));
+
+        for (i, dir) in dirs.into_iter().enumerate() {
+            // Going clockwise around the hex
+            assert_eq!(Hex::from(dir).rotate_right(), Hex::from(dirs[(i + 1) % 6]));
+            assert_eq!(dir.to_string().parse::<FlatHexDir>(), Ok(dir));
+        }
+
+        // Examples from 2017 day 11
+        let distance = |path| {
+            FlatHexDir::parse_path(path)
+                .unwrap()
+                .into_iter()
+                .fold(Hex(0, 0), |h, d| h + d)
+                .length()
+        };
+        assert_eq!(distance("ne,ne,ne"), 3);
+        assert_eq!(distance("ne,ne,sw,sw"), 0);
+        assert_eq!(distance("ne,ne,s,s"), 2);
+        assert_eq!(distance("se,sw,se,sw,sw"), 3);
+        assert_eq!(distance("nnes"), 1);
+
+        assert_eq!(
+            FlatHexDir::parse_path("n,e"),
+            Err(ParseHexDirError("e".to_string()))
+        );
+        assert_eq!(
+            FlatHexDir::parse_path("n w"),
+            Err(ParseHexDirError("w".to_string()))
+        );
+    }
+}
@@ -1,2 +1,6 @@
+pub mod automaton;
 pub mod grid_2d;
+pub mod grid_3d;
+pub mod hex;
+pub mod search;
 pub mod visualize;
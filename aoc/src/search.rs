@@ -0,0 +1,720 @@
+use num::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A path found by one of the search algorithms, from the start state to a
+/// goal state (inclusive on both ends).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// Total cost of the path
+    pub cost: C,
+    /// States along the path, starting with the start state
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    /// The state the path starts from
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    /// The goal state the path ends at
+    pub fn end(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+
+    /// Number of steps taken along the path. This is one less than the number
+    /// of states, since the start state doesn't count as a step.
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+}
+
+/// A visit to a state in Dijkstra / A*
+///
+/// `estimated_total_cost` is the cost to get to this state plus the
+/// heuristic-estimated cost from here to the goal, which is used to
+/// prioritize the frontier.
+struct Visit<S, C> {
+    state: S,
+    /// The cost to get to this state
+    current_cost: C,
+    /// Heuristic-estimated cost to the goal state, for A*
+    estimated_total_cost: C,
+}
+
+impl<S, C: Ord> Ord for Visit<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse ordering, so that the smallest cost is at the top
+        // (making this a min-heap)
+        other.estimated_total_cost.cmp(&self.estimated_total_cost)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Visit<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Visit<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Visit<S, C> {}
+
+/// Walk back through `parents` from `end` to build the path that led to it
+fn reconstruct_path<S>(parents: &HashMap<S, S>, end: S) -> Vec<S>
+where
+    S: Hash + Eq + Clone,
+{
+    let mut path = vec![end];
+
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Breadth-first search from `start` until a state satisfying `is_goal` is found.
+///
+/// Every step has a cost of 1, so the returned path has the fewest steps possible.
+///
+/// # Arguments
+/// * `start` - The state to start searching from
+/// * `successors` - A function returning the states reachable in one step from a state
+/// * `is_goal` - A function determining whether a state is the goal
+///
+/// # Examples
+/// ```
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::search::bfs;
+///
+/// let board = Board::from_str(
+///     "..#\n\
+///      #..\n\
+///      ...",
+/// );
+///
+/// let path = bfs(
+///     Coord(0, 0),
+///     |c| {
+///         c.cardinal_neighbours()
+///             .into_iter()
+///             .filter(|n| matches!(board.get(n), Some('.')))
+///     },
+///     |c| *c == Coord(2, 2),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(path.cost, 4);
+/// assert_eq!(path.states[0], Coord(0, 0));
+/// ```
+pub fn bfs<S, FN, IN, FG>(start: S, mut successors: FN, mut is_goal: FG) -> Option<Path<S, u32>>
+where
+    S: Hash + Eq + Clone,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    // States that have been added to the frontier at some point
+    let mut seen: HashSet<S> = HashSet::from([start.clone()]);
+    let mut parents: HashMap<S, S> = HashMap::new();
+    let mut to_visit: VecDeque<(S, u32)> = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = to_visit.pop_front() {
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct_path(&parents, state),
+            });
+        }
+
+        for next in successors(&state) {
+            if !seen.insert(next.clone()) {
+                // Already been here (or will be, at no greater cost)
+                continue;
+            }
+
+            parents.insert(next.clone(), state.clone());
+            to_visit.push_back((next, cost + 1));
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from `start` until a state satisfying `is_goal` is found.
+///
+/// Equivalent to [`astar`] with a heuristic that always returns zero.
+///
+/// # Arguments
+/// * `start` - The state to start searching from
+/// * `successors` - A function returning `(state, cost)` pairs for the states
+///   reachable in one step from a state, and the cost of taking that step
+/// * `is_goal` - A function determining whether a state is the goal
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, successors: FN, is_goal: FG) -> Option<Path<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `start` until a state satisfying `is_goal` is found.
+///
+/// The heuristic must never over-estimate the remaining cost to the goal,
+/// otherwise the path found may not be the cheapest.
+///
+/// # Arguments
+/// * `start` - The state to start searching from
+/// * `successors` - A function returning `(state, cost)` pairs for the states
+///   reachable in one step from a state, and the cost of taking that step
+/// * `heuristic` - A function estimating the cost from a state to the goal
+/// * `is_goal` - A function determining whether a state is the goal
+///
+/// # Examples
+/// ```
+/// use aoc::grid_2d::{Coord, Dir};
+/// use aoc::search::astar;
+///
+/// // The state has to include direction, since rotating costs 1000
+/// let start = (Coord(0, 0), Dir::East);
+/// let end: Coord = Coord(-2, 1);
+///
+/// let path = astar(
+///     start,
+///     |&(position, facing)| {
+///         [
+///             ((position + facing, facing), 1),
+///             ((position, facing.rotate_right()), 1000),
+///             ((position, facing.rotate_left()), 1000),
+///         ]
+///     },
+///     |(position, _)| position.manhattan_distance(&end),
+///     |(position, _)| *position == end,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(path.cost, 1003);
+/// assert_eq!(path.steps(), 4);
+/// ```
+pub fn astar<S, C, FN, IN, FH, FG>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    // Minimum cost found so far of getting to each state
+    let mut costs: HashMap<S, C> = HashMap::from([(start.clone(), C::zero())]);
+    // The state each state was reached from along its cheapest path
+    let mut parents: HashMap<S, S> = HashMap::new();
+    // States we've already expanded
+    let mut visited: HashSet<S> = HashSet::new();
+    // Frontier, ordered by minimum estimated cost
+    let mut to_visit: BinaryHeap<Visit<S, C>> = BinaryHeap::new();
+
+    to_visit.push(Visit {
+        estimated_total_cost: heuristic(&start),
+        current_cost: C::zero(),
+        state: start,
+    });
+
+    while let Some(Visit {
+        state,
+        current_cost,
+        ..
+    }) = to_visit.pop()
+    {
+        if !visited.insert(state.clone()) {
+            // Already been here
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path {
+                cost: current_cost,
+                states: reconstruct_path(&parents, state),
+            });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            if visited.contains(&next) {
+                continue;
+            }
+
+            let new_cost = current_cost + step_cost;
+
+            let is_cheaper = costs.get(&next).is_none_or(|&current| new_cost < current);
+
+            if is_cheaper {
+                costs.insert(next.clone(), new_cost);
+                parents.insert(next.clone(), state.clone());
+                to_visit.push(Visit {
+                    estimated_total_cost: new_cost + heuristic(&next),
+                    current_cost: new_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Every optimal path from a start state to the cheapest goal state(s), stored
+/// as a DAG of each state's optimal predecessors.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    /// Cost of every optimal path
+    pub cost: C,
+    /// The state all paths start from
+    pub start: S,
+    /// Goal states that can be reached with the optimal cost
+    pub ends: Vec<S>,
+    /// Map from a state to its parents.
+    ///
+    /// The parents of a state are the states that can lead to it,
+    /// all of which have an equal and lowest cost.
+    pub parents: HashMap<S, Vec<S>>,
+}
+
+impl<S, C> ShortestPaths<S, C>
+where
+    S: Hash + Eq + Clone,
+{
+    /// Lazily iterate over every optimal path, each starting with the start
+    /// state and ending with one of the goal states.
+    ///
+    /// There can be exponentially many optimal paths, so prefer
+    /// [`ShortestPaths::states`] if only the states along them are needed.
+    pub fn paths(&self) -> PathsIter<'_, S, C> {
+        PathsIter {
+            shortest_paths: self,
+            stack: self.ends.iter().map(|end| (0, end)).collect(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Get the set of states that lie on any optimal path
+    pub fn states(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = HashSet::new();
+        let mut to_visit: Vec<&S> = self.ends.iter().collect();
+
+        while let Some(state) = to_visit.pop() {
+            if !states.insert(state.clone()) {
+                continue;
+            }
+
+            if let Some(parents) = self.parents.get(state) {
+                to_visit.extend(parents.iter());
+            }
+        }
+
+        states
+    }
+}
+
+/// Iterator over every optimal path in a [`ShortestPaths`], created by
+/// [`ShortestPaths::paths`].
+///
+/// Runs a DFS backwards from the goal states through the parents DAG.
+pub struct PathsIter<'a, S, C> {
+    shortest_paths: &'a ShortestPaths<S, C>,
+    /// States yet to be explored, and how far back from the goal they are
+    stack: Vec<(usize, &'a S)>,
+    /// The path currently being explored, backwards from the goal
+    path: Vec<S>,
+}
+
+impl<S, C> Iterator for PathsIter<'_, S, C>
+where
+    S: Hash + Eq + Clone,
+{
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((depth, state)) = self.stack.pop() {
+            // Drop whatever part of the path belonged to the previous branch
+            self.path.truncate(depth);
+            self.path.push(state.clone());
+
+            if *state == self.shortest_paths.start {
+                return Some(self.path.iter().rev().cloned().collect());
+            }
+
+            if let Some(parents) = self.shortest_paths.parents.get(state) {
+                self.stack
+                    .extend(parents.iter().map(|parent| (depth + 1, parent)));
+            }
+        }
+
+        None
+    }
+}
+
+/// Breadth-first search for every path with the fewest steps from `start`
+/// to a state satisfying `is_goal`.
+///
+/// See [`bfs`] for a description of the arguments.
+///
+/// # Examples
+/// ```
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::search::bfs_all;
+///
+/// let board = Board::from_str(
+///     "...\n\
+///      .#.\n\
+///      ...",
+/// );
+///
+/// let shortest = bfs_all(
+///     Coord(0, 0),
+///     |c| {
+///         c.cardinal_neighbours()
+///             .into_iter()
+///             .filter(|n| matches!(board.get(n), Some('.')))
+///     },
+///     |c| *c == Coord(2, 2),
+/// )
+/// .unwrap();
+///
+/// // Around either side of the wall
+/// assert_eq!(shortest.cost, 4);
+/// assert_eq!(shortest.paths().count(), 2);
+/// assert_eq!(shortest.states().len(), 8);
+/// ```
+pub fn bfs_all<S, FN, IN, FG>(
+    start: S,
+    mut successors: FN,
+    is_goal: FG,
+) -> Option<ShortestPaths<S, u32>>
+where
+    S: Hash + Eq + Clone,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    dijkstra_all(
+        start,
+        |state| successors(state).into_iter().map(|next| (next, 1)),
+        is_goal,
+    )
+}
+
+/// Dijkstra's algorithm for every cheapest path from `start` to a state
+/// satisfying `is_goal`.
+///
+/// See [`dijkstra`] for a description of the arguments.
+pub fn dijkstra_all<S, C, FN, IN, FG>(
+    start: S,
+    successors: FN,
+    is_goal: FG,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar_all(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search for every cheapest path from `start` to a state satisfying `is_goal`.
+///
+/// All goal states reachable with the optimal cost are included. Step costs
+/// must be positive, and the heuristic must never over-estimate the remaining
+/// cost to the goal.
+///
+/// See [`astar`] for a description of the arguments.
+pub fn astar_all<S, C, FN, IN, FH, FG>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut costs: HashMap<S, C> = HashMap::from([(start.clone(), C::zero())]);
+    let mut parents: HashMap<S, Vec<S>> = HashMap::new();
+    let mut visited: HashSet<S> = HashSet::new();
+    let mut to_visit: BinaryHeap<Visit<S, C>> = BinaryHeap::new();
+
+    // Cost of the cheapest goal, once one has been found
+    let mut best_cost: Option<C> = None;
+    let mut ends: Vec<S> = Vec::new();
+
+    to_visit.push(Visit {
+        estimated_total_cost: heuristic(&start),
+        current_cost: C::zero(),
+        state: start.clone(),
+    });
+
+    while let Some(Visit {
+        state,
+        current_cost,
+        estimated_total_cost,
+    }) = to_visit.pop()
+    {
+        if best_cost.is_some_and(|best| estimated_total_cost > best) {
+            // Everything left is more expensive than the goals we've found
+            break;
+        }
+
+        if !visited.insert(state.clone()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            best_cost = Some(current_cost);
+            ends.push(state);
+            continue;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let new_cost = current_cost + step_cost;
+
+            let cost_comparison = costs
+                .get(&next)
+                .map_or(Ordering::Less, |&current| new_cost.cmp(&current));
+
+            match cost_comparison {
+                Ordering::Less => {
+                    costs.insert(next.clone(), new_cost);
+                    to_visit.push(Visit {
+                        estimated_total_cost: new_cost + heuristic(&next),
+                        current_cost: new_cost,
+                        state: next.clone(),
+                    });
+                    // When a new cheapest path is found, reset the parents of this state
+                    parents.insert(next, vec![state.clone()]);
+                }
+                Ordering::Equal => {
+                    // If the cost is the same, this is another optimal way
+                    // to get there. The state may already have been visited
+                    // if the heuristic tied.
+                    parents.entry(next).or_default().push(state.clone());
+                }
+                Ordering::Greater => {}
+            }
+        }
+    }
+
+    best_cost.map(|cost| ShortestPaths {
+        cost,
+        start,
+        ends,
+        parents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_2d::{Board, Coord, Dir};
+
+    fn open_neighbours(board: &Board<char>, c: &Coord) -> Vec<Coord> {
+        c.cardinal_neighbours()
+            .into_iter()
+            .filter(|n| matches!(board.get(n), Some('.')))
+            .collect()
+    }
+
+    fn maze() -> Board<char> {
+        Board::from_str(
+            ".....\n\
+             ####.\n\
+             .....\n\
+             .####\n\
+             .....",
+        )
+    }
+
+    #[test]
+    fn test_bfs() {
+        let board = maze();
+        let path = bfs(
+            Coord(0, 0),
+            |c| open_neighbours(&board, c),
+            |c| *c == Coord(4, 4),
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 16);
+        assert_eq!(path.steps(), 16);
+        assert_eq!(path.start(), &Coord(0, 0));
+        assert_eq!(path.end(), &Coord(4, 4));
+
+        // Every step in the path should move to a neighbour
+        for (a, b) in path.states.iter().zip(path.states.iter().skip(1)) {
+            assert_eq!(a.manhattan_distance(b), 1);
+        }
+    }
+
+    #[test]
+    fn test_bfs_start_is_goal() {
+        let path = bfs(0, |&n| [n + 1], |&n| n == 0).unwrap();
+
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, vec![0]);
+    }
+
+    #[test]
+    fn test_bfs_unreachable() {
+        let board = Board::from_str(".#.");
+        let path = bfs(
+            Coord(0, 0),
+            |c| open_neighbours(&board, c),
+            |c| *c == Coord(0, 2),
+        );
+
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn test_dijkstra_weighted() {
+        // 0 -> 1 -> 2 -> 3 is cheaper than going 0 -> 3 directly
+        let edges: HashMap<u32, Vec<(u32, u32)>> = HashMap::from([
+            (0, vec![(1, 1), (3, 10)]),
+            (1, vec![(2, 2)]),
+            (2, vec![(3, 3)]),
+        ]);
+
+        let path = dijkstra(
+            0,
+            |n| edges.get(n).cloned().unwrap_or_default(),
+            |&n| n == 3,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_astar_matches_bfs() {
+        let board = maze();
+        let target = Coord(4, 4);
+        let path = astar(
+            Coord(0, 0),
+            |c| open_neighbours(&board, c).into_iter().map(|n| (n, 1)),
+            |c| c.manhattan_distance(&target),
+            |c| *c == target,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 16);
+        assert_eq!(path.states.len(), 17);
+    }
+
+    #[test]
+    fn test_bfs_all_open_grid() {
+        let board = Board::from_size((3, 3), '.');
+        let shortest = bfs_all(
+            Coord(0, 0),
+            |c| open_neighbours(&board, c),
+            |c| *c == Coord(2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(shortest.cost, 4);
+        assert_eq!(shortest.ends, vec![Coord(2, 2)]);
+
+        // 4 choose 2 ways to arrange 2 downs and 2 rights
+        let paths: HashSet<Vec<Coord>> = shortest.paths().collect();
+        assert_eq!(paths.len(), 6);
+        for path in paths.iter() {
+            assert_eq!(path.len(), 5);
+            assert_eq!(path[0], Coord(0, 0));
+            assert_eq!(path[4], Coord(2, 2));
+        }
+
+        assert_eq!(shortest.states().len(), 9);
+    }
+
+    #[test]
+    fn test_astar_all_multiple_ends() {
+        // Reaching the end facing either direction counts as a goal, but
+        // only the orientation that doesn't require an extra turn is optimal
+        let board = maze();
+        let end = Coord(4, 4);
+        let shortest = astar_all(
+            (Coord(0, 0), Dir::East),
+            |&(position, facing)| {
+                let mut options = vec![
+                    ((position, facing.rotate_right()), 1000),
+                    ((position, facing.rotate_left()), 1000),
+                ];
+                if matches!(board.get(&(position + facing)), Some('.')) {
+                    options.push(((position + facing, facing), 1));
+                }
+                options
+            },
+            |(position, _)| position.manhattan_distance(&end),
+            |(position, _)| *position == end,
+        )
+        .unwrap();
+
+        assert_eq!(shortest.cost, 4016);
+        assert_eq!(shortest.ends, vec![(end, Dir::East)]);
+        assert_eq!(shortest.paths().count(), 1);
+
+        let tiles: HashSet<Coord> = shortest.states().into_iter().map(|(c, _)| c).collect();
+        assert_eq!(tiles.len(), 17);
+    }
+
+    #[test]
+    fn test_dijkstra_all_branches() {
+        // Two equal cost routes from 0 to 3, and a more expensive one
+        let edges: HashMap<u32, Vec<(u32, u32)>> = HashMap::from([
+            (0, vec![(1, 1), (2, 2), (3, 5)]),
+            (1, vec![(3, 2)]),
+            (2, vec![(3, 1)]),
+        ]);
+
+        let shortest = dijkstra_all(
+            0,
+            |n| edges.get(n).cloned().unwrap_or_default(),
+            |&n| n == 3,
+        )
+        .unwrap();
+
+        assert_eq!(shortest.cost, 3);
+
+        let mut paths: Vec<Vec<u32>> = shortest.paths().collect();
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+        assert_eq!(shortest.states(), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_all_unreachable() {
+        let shortest = bfs_all(0u32, |&n| (n < 5).then_some(n + 1), |&n| n == 10);
+
+        assert!(shortest.is_none());
+    }
+}
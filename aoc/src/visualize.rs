@@ -1,5 +1,16 @@
 use std::io::stdin;
 
+mod animation;
+mod heatmap;
+mod image;
+mod style;
+pub mod svg;
+
+pub use animation::Animation;
+pub use heatmap::{Gradient, Heatmap};
+pub use image::{encode_gif, save_gif, Image, ImageError};
+pub use style::{colors_enabled, Color, Renderer, Style};
+
 /// Prompt for user input
 pub fn prompt(text: &str) -> String {
     println!("{}", text);
@@ -0,0 +1,411 @@
+use std::fmt::Display;
+use std::io::{stdin, stdout, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Slowest and fastest speeds that playback can be changed to, in frames per
+/// second
+const MIN_FPS: f64 = 0.125;
+const MAX_FPS: f64 = 1000.0;
+
+/// A frame of an animation, rendered when it's shown
+type FrameFn<'a> = Box<dyn Fn() -> String + 'a>;
+
+/// A sequence of frames that can be played back in the terminal.
+///
+/// Frames are redrawn in place, rather than scrolling the terminal. While
+/// playing:
+///
+/// * `space` pauses and unpauses
+/// * `l` or `→` steps forward a frame, and `h` or `←` steps back
+/// * `r` rewinds to the first frame
+/// * `+` and `-` change the speed
+/// * `q` quits
+///
+/// Playback pauses on the last frame until `q` is pressed.
+///
+/// # Examples
+/// ```no_run
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::visualize::{Animation, Renderer};
+///
+/// let mut board = Board::from_size((5, 5), '.');
+/// let mut animation = Animation::new().with_fps(4.0);
+///
+/// for i in 0..5 {
+///     board[Coord(i, i)] = '#';
+///     animation.push(Renderer::new(&board));
+/// }
+///
+/// animation.play();
+/// ```
+pub struct Animation<'a> {
+    frames: Vec<FrameFn<'a>>,
+    fps: f64,
+}
+
+impl Default for Animation<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Animation<'a> {
+    /// Create an empty animation, playing at 10 frames per second
+    pub fn new() -> Self {
+        Animation {
+            frames: Vec::new(),
+            fps: 10.0,
+        }
+    }
+
+    /// Set how many frames to show per second
+    ///
+    /// # Panics
+    /// Panics if `fps` isn't a positive, finite number
+    pub fn with_fps(mut self, fps: f64) -> Self {
+        assert!(fps > 0.0 && fps.is_finite(), "Invalid fps {}", fps);
+        self.fps = fps;
+        self
+    }
+
+    /// Add a frame, rendering it straight away
+    pub fn push<D: Display>(&mut self, frame: D) {
+        let text = frame.to_string();
+        self.frames.push(Box::new(move || text.clone()));
+    }
+
+    /// Add a frame that is rendered by a closure each time it's shown
+    pub fn push_with<F>(&mut self, render: F)
+    where
+        F: Fn() -> String + 'a,
+    {
+        self.frames.push(Box::new(render));
+    }
+
+    /// Number of frames in the animation
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Render a single frame
+    pub fn frame(&self, i: usize) -> String {
+        (self.frames[i])()
+    }
+
+    /// Play the animation in the terminal.
+    ///
+    /// Returns the index of the frame being shown when playback stopped, or
+    /// `None` if there aren't any frames. If stdout isn't a terminal, every
+    /// frame is just printed one after the other instead.
+    ///
+    /// Key presses are read from stdin on a background thread. Reading stdin
+    /// can't be interrupted, so that thread is left waiting for input after
+    /// playback stops, and swallows the next key press or line typed.
+    pub fn play(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        if !stdout().is_terminal() {
+            for i in 0..self.len() {
+                let frame = self.frame(i);
+                println!("Frame {}/{}", i + 1, self.len());
+                print!("{}", frame);
+                if !frame.ends_with('\n') {
+                    println!();
+                }
+            }
+            return Some(self.len() - 1);
+        }
+
+        let _terminal = TerminalMode::enable();
+        let keys = spawn_key_reader();
+        let mut player = Player::new(self.len(), self.fps);
+        let mut drawn_lines = 0;
+
+        loop {
+            drawn_lines = self.draw(&player, drawn_lines);
+
+            let key = if player.paused {
+                keys.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                keys.recv_timeout(player.frame_duration())
+            };
+
+            match key {
+                Ok(key) => {
+                    if !player.press(key) {
+                        break;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => player.tick(),
+                // No more input, so nothing can unpause or quit
+                Err(RecvTimeoutError::Disconnected) if player.paused => break,
+                Err(RecvTimeoutError::Disconnected) => player.tick(),
+            }
+        }
+
+        Some(player.frame)
+    }
+
+    /// Draw the current frame over the previous one, which took up
+    /// `previous_lines` lines. Returns the number of lines drawn.
+    fn draw(&self, player: &Player, previous_lines: usize) -> usize {
+        let frame = self.frame(player.frame);
+        let status = format!(
+            "Frame {}/{} at {:.1} fps{}  [space] pause  [h/l] step  [r] rewind  [+/-] speed  [q] quit",
+            player.frame + 1,
+            self.len(),
+            player.fps,
+            if player.paused { " (paused)" } else { "" },
+        );
+
+        let mut out = stdout().lock();
+
+        // Move back up to the top of the previous frame, and clear it
+        if previous_lines > 0 {
+            write!(out, "\x1b[{}A\r\x1b[J", previous_lines).unwrap();
+        }
+        write!(out, "{}", frame).unwrap();
+        if !frame.ends_with('\n') {
+            writeln!(out).unwrap();
+        }
+        writeln!(out, "{}", status).unwrap();
+        out.flush().unwrap();
+
+        frame.lines().count() + 1
+    }
+}
+
+/// A key press that controls playback
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Pause,
+    Forward,
+    Back,
+    Rewind,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Parse key presses out of raw terminal input. Unknown keys are ignored.
+fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let key = match bytes[i..] {
+            // Arrow keys are sent as escape sequences
+            [0x1b, b'[', b'C', ..] => {
+                i += 2;
+                Some(Key::Forward)
+            }
+            [0x1b, b'[', b'D', ..] => {
+                i += 2;
+                Some(Key::Back)
+            }
+            [b' ', ..] | [b'p', ..] => Some(Key::Pause),
+            [b'l', ..] | [b'.', ..] => Some(Key::Forward),
+            [b'h', ..] | [b',', ..] => Some(Key::Back),
+            [b'r', ..] => Some(Key::Rewind),
+            [b'+', ..] | [b'=', ..] => Some(Key::Faster),
+            [b'-', ..] => Some(Key::Slower),
+            [b'q', ..] | [0x03, ..] => Some(Key::Quit),
+            _ => None,
+        };
+
+        keys.extend(key);
+        i += 1;
+    }
+
+    keys
+}
+
+/// Playback state of an animation
+#[derive(Debug, Clone, PartialEq)]
+struct Player {
+    frame: usize,
+    len: usize,
+    fps: f64,
+    paused: bool,
+}
+
+impl Player {
+    fn new(len: usize, fps: f64) -> Self {
+        Player {
+            frame: 0,
+            len,
+            fps,
+            paused: false,
+        }
+    }
+
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    /// Move on to the next frame, pausing at the end
+    fn tick(&mut self) {
+        if self.frame + 1 < self.len {
+            self.frame += 1;
+        }
+        if self.frame + 1 == self.len {
+            self.paused = true;
+        }
+    }
+
+    /// Handle a key press. Returns false if playback should stop.
+    fn press(&mut self, key: Key) -> bool {
+        match key {
+            Key::Pause => self.paused = !self.paused,
+            Key::Forward => {
+                self.paused = true;
+                self.frame = (self.frame + 1).min(self.len - 1);
+            }
+            Key::Back => {
+                self.paused = true;
+                self.frame = self.frame.saturating_sub(1);
+            }
+            Key::Rewind => self.frame = 0,
+            Key::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+            Key::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+            Key::Quit => return false,
+        }
+
+        true
+    }
+}
+
+/// Puts the terminal into a mode where key presses are read straight away
+/// without being echoed, restoring it when dropped.
+///
+/// Uses `stty`, so does nothing if that isn't available.
+struct TerminalMode;
+
+impl TerminalMode {
+    fn enable() -> Self {
+        stty(&["-icanon", "-echo", "min", "1"]);
+        print!("\x1b[?25l");
+        TerminalMode
+    }
+}
+
+impl Drop for TerminalMode {
+    fn drop(&mut self) {
+        stty(&["icanon", "echo"]);
+        print!("\x1b[?25h");
+        stdout().flush().unwrap();
+    }
+}
+
+fn stty(args: &[&str]) {
+    let _ = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .status();
+}
+
+/// Read key presses from stdin on another thread.
+///
+/// The thread carries on reading stdin until it closes, so it will swallow
+/// input meant for anything else afterwards.
+fn spawn_key_reader() -> Receiver<Key> {
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        let mut buffer = [0u8; 16];
+
+        while let Ok(n @ 1..) = stdin().read(&mut buffer) {
+            for key in parse_keys(&buffer[..n]) {
+                if sender.send(key).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            parse_keys(b" l\x1b[Dxq"),
+            vec![Key::Pause, Key::Forward, Key::Back, Key::Quit]
+        );
+        assert_eq!(
+            parse_keys(b"\x1b[C+-r"),
+            vec![Key::Forward, Key::Faster, Key::Slower, Key::Rewind]
+        );
+        assert_eq!(parse_keys(b"\x1b"), vec![]);
+    }
+
+    #[test]
+    fn test_player() {
+        let mut player = Player::new(3, 10.0);
+
+        player.tick();
+        assert_eq!((player.frame, player.paused), (1, false));
+
+        // Pauses at the end
+        player.tick();
+        assert_eq!((player.frame, player.paused), (2, true));
+        player.press(Key::Forward);
+        assert_eq!(player.frame, 2);
+
+        player.press(Key::Back);
+        player.press(Key::Back);
+        player.press(Key::Back);
+        assert_eq!(player.frame, 0);
+
+        player.press(Key::Faster);
+        assert_eq!(player.frame_duration(), Duration::from_millis(50));
+
+        // Speed changes stop at the limits
+        for _ in 0..20 {
+            player.press(Key::Faster);
+        }
+        assert_eq!(player.fps, MAX_FPS);
+        for _ in 0..20 {
+            player.press(Key::Slower);
+        }
+        assert_eq!(player.fps, MIN_FPS);
+
+        assert!(!player.press(Key::Quit));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_fps() {
+        Animation::new().with_fps(0.0);
+    }
+
+    #[test]
+    fn test_frames() {
+        let counter = std::cell::Cell::new(0);
+        let mut animation = Animation::new();
+
+        animation.push("a\nb");
+        animation.push_with(|| {
+            counter.set(counter.get() + 1);
+            format!("render {}", counter.get())
+        });
+
+        assert_eq!(animation.len(), 2);
+        assert_eq!(animation.frame(0), "a\nb");
+        assert_eq!(animation.frame(1), "render 1");
+        assert_eq!(animation.frame(1), "render 2");
+    }
+}
@@ -0,0 +1,423 @@
+use super::{colors_enabled, Color, Image, Style};
+use crate::grid_2d::{Board, Coord, Rect};
+use num::ToPrimitive;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// A sequence of colors that numbers are spread across
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gradient {
+    stops: Vec<(u8, u8, u8)>,
+}
+
+impl Gradient {
+    /// A gradient going evenly through each of the colors in turn.
+    ///
+    /// # Panics
+    /// Panics if there are no colors
+    pub fn new(colors: &[Color]) -> Self {
+        assert!(!colors.is_empty(), "A gradient needs at least one color");
+
+        Gradient {
+            stops: colors.iter().map(|c| c.rgb()).collect(),
+        }
+    }
+
+    /// Dark purple through blue and green to yellow. Easy to read, even when
+    /// colorblind.
+    pub fn viridis() -> Self {
+        Gradient::new(&[
+            Color::Rgb(68, 1, 84),
+            Color::Rgb(59, 82, 139),
+            Color::Rgb(33, 145, 140),
+            Color::Rgb(94, 201, 98),
+            Color::Rgb(253, 231, 37),
+        ])
+    }
+
+    /// Black through red and yellow to white
+    pub fn heat() -> Self {
+        Gradient::new(&[
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(190, 30, 0),
+            Color::Rgb(255, 200, 0),
+            Color::Rgb(255, 255, 255),
+        ])
+    }
+
+    /// Black to white
+    pub fn grayscale() -> Self {
+        Gradient::new(&[Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)])
+    }
+
+    /// The color `t` of the way along the gradient, where `t` is clamped
+    /// between 0 and 1
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::visualize::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new(&[Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)]);
+    ///
+    /// assert_eq!(gradient.at(0.5), Color::Rgb(100, 50, 0));
+    /// assert_eq!(gradient.at(7.0), Color::Rgb(200, 100, 0));
+    /// ```
+    pub fn at(&self, t: f64) -> Color {
+        let segments = self.stops.len() - 1;
+        let position = t.clamp(0.0, 1.0) * segments as f64;
+
+        // Which pair of stops it's between, and how far between them
+        let i = (position.floor() as usize).min(segments.saturating_sub(1));
+        let fraction = position - i as f64;
+
+        let from = self.stops[i];
+        let to = self.stops[(i + 1).min(segments)];
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Gradient::viridis()
+    }
+}
+
+/// Characters from least to most dense, for showing values without colors.
+/// Cells without a value are left blank.
+const RAMP: &[char] = &['.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// Number of cells in the legend's color bar
+const LEGEND_WIDTH: usize = 32;
+
+/// Shows numbers laid out on a grid as colors.
+///
+/// Values are scaled so that the smallest is at the start of the
+/// [`Gradient`] and the largest is at the end, unless a range is set. Cells
+/// without a value are shown in a separate color.
+///
+/// In the terminal, each cell is two characters wide to keep it roughly
+/// square, or with [`Heatmap::half_blocks`] each character shows two cells
+/// stacked on top of each other, fitting twice as many rows on the screen.
+/// A legend underneath shows which colors are which values.
+///
+/// # Examples
+/// ```no_run
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::visualize::{Gradient, Heatmap};
+///
+/// let board = Board::from_str("S..\n.#.\n..E");
+/// let distances = board.distance_map([Coord(0, 0)], |c| *c != '#');
+///
+/// let heatmap = Heatmap::from_board_with(&distances, |d| d.map(f64::from))
+///     .gradient(Gradient::heat());
+///
+/// heatmap.print();
+/// heatmap.to_image(10).save("distances.png").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    values: Board<Option<f64>>,
+    gradient: Gradient,
+    range: Option<(f64, f64)>,
+    missing: Color,
+    half_blocks: bool,
+    color: bool,
+}
+
+impl Heatmap {
+    fn new(values: Board<Option<f64>>) -> Self {
+        Heatmap {
+            values,
+            gradient: Gradient::default(),
+            range: None,
+            missing: Color::Rgb(40, 40, 40),
+            half_blocks: false,
+            color: colors_enabled(),
+        }
+    }
+
+    /// Show a board of numbers
+    pub fn from_board<N>(board: &Board<N>) -> Self
+    where
+        N: ToPrimitive,
+    {
+        Self::from_board_with(board, |n| n.to_f64())
+    }
+
+    /// Show a board, getting the value of each cell with `value`. Cells where
+    /// it returns `None` don't have a value.
+    pub fn from_board_with<T, F>(board: &Board<T>, value: F) -> Self
+    where
+        F: Fn(&T) -> Option<f64>,
+    {
+        Self::new(Board::from_fn(board.size(), |c| value(&board[c])))
+    }
+
+    /// Show numbers at scattered coordinates. The heatmap covers the smallest
+    /// rectangle containing all of them, and is empty if the map is.
+    pub fn from_map<N>(map: &HashMap<Coord, N>) -> Self
+    where
+        N: ToPrimitive,
+    {
+        let coords: Vec<Coord> = map.keys().copied().collect();
+        let Some(bounds) = Rect::bounding_box(&coords) else {
+            return Self::new(Board::new(Vec::new()));
+        };
+
+        Self::new(Board::from_fn(bounds.size(), |c| {
+            map.get(&(c + bounds.min())).and_then(|n| n.to_f64())
+        }))
+    }
+
+    /// Set the colors to use
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = gradient;
+        self
+    }
+
+    /// Set the values at the start and end of the gradient, instead of using
+    /// the smallest and largest values. Anything outside the range gets the
+    /// color at the nearest end.
+    ///
+    /// # Panics
+    /// Panics if `min` isn't less than `max`
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        assert!(min < max, "Invalid heatmap range {}..{}", min, max);
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Set the color of cells without a value
+    pub fn missing(mut self, color: Color) -> Self {
+        self.missing = color;
+        self
+    }
+
+    /// Show two rows of cells per line of the terminal.
+    ///
+    /// This needs colors, so it has no effect when they're off: each cell is
+    /// still shown as two characters on its own line.
+    pub fn half_blocks(mut self) -> Self {
+        self.half_blocks = true;
+        self
+    }
+
+    /// Force colors on or off, instead of detecting whether they're
+    /// supported. Without colors, values are shown with characters of
+    /// different densities.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// The values at the start and end of the gradient, or `None` if there
+    /// aren't any values
+    pub fn bounds(&self) -> Option<(f64, f64)> {
+        if self.range.is_some() {
+            return self.range;
+        }
+
+        self.values
+            .iter()
+            .filter_map(|(_, v)| *v)
+            .fold(None, |bounds, v| match bounds {
+                None => Some((v, v)),
+                Some((min, max)) => Some((v.min(min), v.max(max))),
+            })
+    }
+
+    /// How far along the gradient a cell is, or `None` if it doesn't have a
+    /// value. Takes the bounds so they're only found once per drawing.
+    fn position(&self, c: Coord, bounds: Option<(f64, f64)>) -> Option<f64> {
+        let value = self.values.get(&c).copied().flatten()?;
+        let (min, max) = bounds?;
+
+        if max > min {
+            Some(((value - min) / (max - min)).clamp(0.0, 1.0))
+        } else {
+            // Every value is the same
+            Some(0.5)
+        }
+    }
+
+    fn color_with(&self, c: Coord, bounds: Option<(f64, f64)>) -> Color {
+        self.position(c, bounds)
+            .map_or(self.missing, |t| self.gradient.at(t))
+    }
+
+    /// The color a cell is shown in
+    pub fn color_at(&self, c: Coord) -> Color {
+        self.color_with(c, self.bounds())
+    }
+
+    /// A bar showing the gradient, labelled with the values at each end
+    pub fn legend(&self) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return "No values".to_string();
+        };
+
+        let bar: String = (0..LEGEND_WIDTH)
+            .map(|i| {
+                let t = i as f64 / (LEGEND_WIDTH - 1) as f64;
+
+                if self.color {
+                    Style::new().bg(self.gradient.at(t)).paint(' ')
+                } else {
+                    ramp(t).to_string()
+                }
+            })
+            .collect();
+
+        format!("{} [{}] {}", format_value(min), bar, format_value(max))
+    }
+
+    /// Draw the heatmap as an image, with each cell as a `scale` by `scale`
+    /// square
+    pub fn to_image(&self, scale: usize) -> Image {
+        let bounds = self.bounds();
+
+        Image::from_board(&self.values, scale, |c, _| self.color_with(c, bounds))
+    }
+
+    /// Print the heatmap and its legend to the terminal
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+}
+
+/// Character for a value `t` of the way between the smallest and largest
+fn ramp(t: f64) -> char {
+    RAMP[(t * (RAMP.len() - 1) as f64).round() as usize]
+}
+
+/// Format a value for the legend, without a pointless `.0` on whole numbers
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+impl Display for Heatmap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (height, width) = (self.values.height() as i32, self.values.width() as i32);
+        let bounds = self.bounds();
+        let color = |row, col| self.color_with(Coord(row, col), bounds);
+
+        if !self.color {
+            for row in 0..height {
+                let line: String = (0..width)
+                    .map(|col| self.position(Coord(row, col), bounds).map_or(' ', ramp))
+                    .flat_map(|c| [c; 2])
+                    .collect();
+                writeln!(f, "{}", line)?;
+            }
+        } else if self.half_blocks {
+            // The top cell is the text color and the bottom cell is the
+            // background, so an odd row at the end just has a blank bottom
+            for row in (0..height).step_by(2) {
+                for col in 0..width {
+                    let mut style = Style::new().fg(color(row, col));
+                    if row + 1 < height {
+                        style = style.bg(color(row + 1, col));
+                    }
+                    write!(f, "{}", style.paint('▀'))?;
+                }
+                writeln!(f)?;
+            }
+        } else {
+            for row in 0..height {
+                for col in 0..width {
+                    let style = Style::new().bg(color(row, col));
+                    write!(f, "{}", style.paint("  "))?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        writeln!(f, "{}", self.legend())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gradient() {
+        let gradient = Gradient::new(&[
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(100, 0, 0),
+            Color::Rgb(100, 200, 0),
+        ]);
+
+        assert_eq!(gradient.at(0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.at(0.25), Color::Rgb(50, 0, 0));
+        assert_eq!(gradient.at(0.5), Color::Rgb(100, 0, 0));
+        assert_eq!(gradient.at(0.75), Color::Rgb(100, 100, 0));
+        assert_eq!(gradient.at(1.0), Color::Rgb(100, 200, 0));
+        assert_eq!(gradient.at(-1.0), Color::Rgb(0, 0, 0));
+
+        let single = Gradient::new(&[Color::Red]);
+        assert_eq!(single.at(0.3), Color::Rgb(205, 0, 0));
+    }
+
+    #[test]
+    fn test_scaling() {
+        let board: Board<u32> = Board::new(vec![vec![0, 5], vec![10, 20]]);
+        let heatmap = Heatmap::from_board(&board).gradient(Gradient::grayscale());
+
+        assert_eq!(heatmap.bounds(), Some((0.0, 20.0)));
+        assert_eq!(heatmap.color_at(Coord(0, 0)), Color::Rgb(0, 0, 0));
+        assert_eq!(heatmap.color_at(Coord(1, 0)), Color::Rgb(128, 128, 128));
+        assert_eq!(heatmap.color_at(Coord(1, 1)), Color::Rgb(255, 255, 255));
+
+        let clamped = heatmap.range(0.0, 10.0);
+        assert_eq!(clamped.color_at(Coord(1, 0)), Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_range() {
+        let board: Board<u32> = Board::new(vec![vec![0, 5]]);
+        Heatmap::from_board(&board).range(5.0, 5.0);
+    }
+
+    #[test]
+    fn test_from_map() {
+        let map = HashMap::from([(Coord(-1, 3), 4), (Coord(1, 4), 8)]);
+        let heatmap = Heatmap::from_map(&map)
+            .with_color(false)
+            .missing(Color::Blue);
+
+        assert_eq!(heatmap.values.size(), (3, 2));
+        assert_eq!(heatmap.color_at(Coord(0, 1)), Color::Blue);
+        assert_eq!(
+            heatmap.to_string(),
+            "..  \n    \n  @@\n4 [..::::----====++++****####%%%%@@] 8\n"
+        );
+
+        let empty = Heatmap::from_map(&HashMap::<Coord, u32>::new());
+        assert_eq!(empty.bounds(), None);
+    }
+
+    #[test]
+    fn test_half_blocks() {
+        let board: Board<f64> = Board::new(vec![vec![0.5], vec![1.5], vec![2.5]]);
+        let heatmap = Heatmap::from_board(&board).half_blocks().with_color(true);
+        let output = heatmap.to_string();
+
+        // Two lines of cells and a legend
+        assert_eq!(output.lines().count(), 3);
+        assert_eq!(output.matches('▀').count(), 2);
+        assert!(output.ends_with("] 2.50\n"));
+        assert!(output.lines().last().unwrap().starts_with("0.50 ["));
+
+        // Without colors every row gets its own line
+        let plain = Heatmap::from_board(&board).half_blocks().with_color(false);
+        assert_eq!(plain.to_string().lines().count(), 4);
+    }
+}
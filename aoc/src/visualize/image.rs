@@ -0,0 +1,579 @@
+use super::Color;
+use crate::grid_2d::{Board, Coord};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+/// An RGB image, which can be saved as a PPM or PNG file, or as frames of an
+/// animated GIF with [`save_gif`].
+///
+/// Pixels are indexed by (row, col) [`Coord`]s, like a [`Board`].
+///
+/// # Examples
+/// ```no_run
+/// use aoc::grid_2d::Board;
+/// use aoc::visualize::{Color, Image};
+///
+/// let board = Board::from_str("#..\n.#.\n..#");
+///
+/// // Each cell becomes a 10x10 square of pixels
+/// let image = Image::from_board(&board, 10, |_, c| match c {
+///     '#' => Color::Green,
+///     _ => Color::Black,
+/// });
+///
+/// image.save("board.png").unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<(u8, u8, u8)>,
+}
+
+impl Image {
+    /// Create an image filled with a single color
+    pub fn new(width: usize, height: usize, fill: Color) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![fill.rgb(); width * height],
+        }
+    }
+
+    /// Draw a board, with each cell as a `scale` by `scale` square of the
+    /// color given by `color`
+    pub fn from_board<T, F>(board: &Board<T>, scale: usize, color: F) -> Self
+    where
+        F: Fn(Coord, &T) -> Color,
+    {
+        let mut image = Image::new(board.width() * scale, board.height() * scale, Color::Black);
+
+        for (c, item) in board.iter() {
+            let rgb = color(c, item).rgb();
+            let (top, left) = (c.0 as usize * scale, c.1 as usize * scale);
+
+            for row in top..top + scale {
+                let start = row * image.width + left;
+                image.pixels[start..start + scale].fill(rgb);
+            }
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, c: &Coord) -> Option<usize> {
+        let (row, col) = (usize::try_from(c.0).ok()?, usize::try_from(c.1).ok()?);
+
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+
+    /// Get the RGB color of a pixel, or `None` if it's outside the image
+    pub fn get(&self, c: &Coord) -> Option<(u8, u8, u8)> {
+        self.index(c).map(|i| self.pixels[i])
+    }
+
+    /// Set the color of a pixel. Pixels outside the image are ignored.
+    pub fn set(&mut self, c: &Coord, color: Color) {
+        if let Some(i) = self.index(c) {
+            self.pixels[i] = color.rgb();
+        }
+    }
+
+    /// Encode the image as a binary PPM file
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+
+        for &(r, g, b) in &self.pixels {
+            out.extend([r, g, b]);
+        }
+
+        out
+    }
+
+    /// Encode the image as a PNG file.
+    ///
+    /// The image data isn't compressed, so this is quick but the files are
+    /// big.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, and the only compression, filter and
+        // interlace methods there are
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Each row starts with the filter type, which is always none
+        let mut data = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            data.push(0);
+            for &(r, g, b) in row {
+                data.extend([r, g, b]);
+            }
+        }
+
+        let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&data));
+        png_chunk(&mut out, b"IEND", &[]);
+
+        out
+    }
+
+    /// Save the image, as a PPM or PNG depending on the file extension
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ImageError> {
+        let path = path.as_ref();
+
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png(),
+            _ => return Err(ImageError::UnknownFormat(path.display().to_string())),
+        };
+
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+}
+
+/// Error from encoding or saving an image
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file couldn't be written
+    Io(io::Error),
+    /// The file extension isn't a supported image format
+    UnknownFormat(String),
+    /// An animation didn't have any frames
+    NoFrames,
+    /// A frame of an animation was a different size than the first frame
+    SizeMismatch { frame: usize },
+    /// GIFs can only use 256 different colors
+    TooManyColors { found: usize },
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageError::Io(error) => write!(f, "Failed to write image: {}", error),
+            ImageError::UnknownFormat(path) => write!(f, "Unknown image format for {}", path),
+            ImageError::NoFrames => write!(f, "No frames to encode"),
+            ImageError::SizeMismatch { frame } => {
+                write!(
+                    f,
+                    "Frame {} is a different size than the first frame",
+                    frame
+                )
+            }
+            ImageError::TooManyColors { found } => {
+                write!(f, "Found {} colors, but GIFs can only use 256", found)
+            }
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ImageError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(error: io::Error) -> Self {
+        ImageError::Io(error)
+    }
+}
+
+/// Encode frames as an animated GIF that loops forever, showing `fps` frames
+/// per second.
+///
+/// # Errors
+/// Returns an error if there are no frames, the frames are different sizes,
+/// or they use more than 256 colors between them.
+pub fn encode_gif(frames: &[Image], fps: f64) -> Result<Vec<u8>, ImageError> {
+    let first = frames.first().ok_or(ImageError::NoFrames)?;
+    let (width, height) = (first.width, first.height);
+
+    if let Some(frame) = frames
+        .iter()
+        .position(|f| (f.width, f.height) != (width, height))
+    {
+        return Err(ImageError::SizeMismatch { frame });
+    }
+
+    // All the frames share one palette
+    let mut palette: Vec<(u8, u8, u8)> = Vec::new();
+    let mut indices: HashMap<(u8, u8, u8), usize> = HashMap::new();
+    for &rgb in frames.iter().flat_map(|f| &f.pixels) {
+        indices.entry(rgb).or_insert_with(|| {
+            palette.push(rgb);
+            palette.len() - 1
+        });
+    }
+
+    if palette.len() > 256 {
+        return Err(ImageError::TooManyColors {
+            found: palette.len(),
+        });
+    }
+
+    // The palette size has to be a power of 2, at least 2 for LZW
+    let palette_bits = (palette.len().max(2).next_power_of_two().trailing_zeros()).max(1);
+    palette.resize(1 << palette_bits, (0, 0, 0));
+
+    let mut out = b"GIF89a".to_vec();
+    out.extend((width as u16).to_le_bytes());
+    out.extend((height as u16).to_le_bytes());
+    // Global palette, with its size
+    out.extend([0x80 | (palette_bits as u8 - 1), 0, 0]);
+    for (r, g, b) in palette {
+        out.extend([r, g, b]);
+    }
+
+    // Loop forever
+    out.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let delay = (100.0 / fps).round() as u16;
+    let min_code_size = palette_bits.max(2) as u8;
+
+    for frame in frames {
+        // Graphic control extension, setting the delay in hundredths of a second
+        out.extend([0x21, 0xf9, 0x04, 0x00]);
+        out.extend(delay.to_le_bytes());
+        out.extend([0x00, 0x00]);
+
+        // Image descriptor covering the whole image, using the global palette
+        out.push(0x2c);
+        out.extend([0, 0, 0, 0]);
+        out.extend((width as u16).to_le_bytes());
+        out.extend((height as u16).to_le_bytes());
+        out.push(0x00);
+
+        let pixels: Vec<u8> = frame.pixels.iter().map(|rgb| indices[rgb] as u8).collect();
+        out.push(min_code_size);
+        for block in lzw_encode(&pixels, min_code_size).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0x00);
+    }
+
+    out.push(0x3b);
+    Ok(out)
+}
+
+/// Save frames as an animated GIF. See [`encode_gif`].
+pub fn save_gif<P: AsRef<Path>>(path: P, frames: &[Image], fps: f64) -> Result<(), ImageError> {
+    std::fs::write(path, encode_gif(frames, fps)?)?;
+    Ok(())
+}
+
+/// Append a PNG chunk, with its length and checksum
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// Wrap data in a zlib stream without compressing it, using deflate's
+/// "stored" blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, and no preset dictionary
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        // Still need one (empty) final block
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+/// Writes codes of varying widths, least significant bit first
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += width;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compress palette indices with the variable-width LZW that GIF uses
+fn lzw_encode(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    const MAX_CODE: u16 = 4096;
+
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        buffer: 0,
+        bits: 0,
+    };
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut width = min_code_size as u32 + 1;
+
+    writer.write(clear, width);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        writer.write(end, width);
+        return writer.finish();
+    };
+    let mut prefix = first as u16;
+
+    for &pixel in rest {
+        if let Some(&code) = dictionary.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+
+        writer.write(prefix, width);
+        dictionary.insert((prefix, pixel), next_code);
+        next_code += 1;
+
+        // The decoder adds each code one step later, so only widens once the
+        // code after this one wouldn't fit
+        if next_code > 1 << width && width < 12 {
+            width += 1;
+        }
+
+        if next_code == MAX_CODE {
+            // The dictionary is full, so start again
+            writer.write(clear, width);
+            dictionary.clear();
+            next_code = end + 1;
+            width = min_code_size as u32 + 1;
+        }
+
+        prefix = pixel as u16;
+    }
+
+    writer.write(prefix, width);
+
+    // The decoder adds a code after reading the last one, which can widen the
+    // end code
+    if next_code + 1 > 1 << width && width < 12 {
+        width += 1;
+    }
+    writer.write(end, width);
+
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode GIF LZW data, to check the encoder round trips
+    fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let end = clear + 1;
+
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut width = min_code_size as u32 + 1;
+        let mut previous: Option<usize> = None;
+        let mut out = Vec::new();
+
+        let (mut buffer, mut bits, mut bytes) = (0u32, 0u32, data.iter());
+
+        loop {
+            while bits < width {
+                buffer |= (*bytes.next().unwrap() as u32) << bits;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << width) - 1)) as usize;
+            buffer >>= width;
+            bits -= width;
+
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                width = min_code_size as u32 + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+
+            let entry = match (table.get(code), previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(p)) => {
+                    let mut entry = table[p].clone();
+                    entry.push(table[p][0]);
+                    entry
+                }
+                (None, None) => panic!("Unknown code {}", code),
+            };
+
+            if let Some(p) = previous {
+                let mut new = table[p].clone();
+                new.push(entry[0]);
+                table.push(new);
+
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+
+            out.extend(&entry);
+            previous = Some(code);
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        // Long enough to fill the dictionary and clear it a few times
+        let mut state = 12345u32;
+        let noisy: Vec<u8> = (0..20_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8 % 5
+            })
+            .collect();
+
+        for (pixels, min_code_size) in [
+            (vec![], 2),
+            (vec![1], 2),
+            (vec![0, 0, 0, 0, 0, 0, 0], 2),
+            (noisy.clone(), 3),
+            (noisy.iter().map(|p| p * 50).collect(), 8),
+        ] {
+            let encoded = lzw_encode(&pixels, min_code_size);
+            assert_eq!(lzw_decode(&encoded, min_code_size), pixels);
+        }
+    }
+
+    #[test]
+    fn test_from_board() {
+        let board = Board::from_str("#.\n.#");
+        let image = Image::from_board(&board, 2, |_, c| match c {
+            '#' => Color::Rgb(255, 0, 0),
+            _ => Color::White,
+        });
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.get(&Coord(1, 1)), Some((255, 0, 0)));
+        assert_eq!(image.get(&Coord(1, 2)), Some(Color::White.rgb()));
+        assert_eq!(image.get(&Coord(3, 3)), Some((255, 0, 0)));
+        assert_eq!(image.get(&Coord(4, 0)), None);
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image::new(3, 2, Color::Blue);
+        let png = image.to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 3, 0, 0, 0, 2]);
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn test_gif_errors() {
+        let small = Image::new(1, 1, Color::Black);
+        let big = Image::new(2, 1, Color::Black);
+
+        assert!(matches!(encode_gif(&[], 1.0), Err(ImageError::NoFrames)));
+        assert!(matches!(
+            encode_gif(&[small.clone(), big], 1.0),
+            Err(ImageError::SizeMismatch { frame: 1 })
+        ));
+
+        let mut colorful = Image::new(300, 1, Color::Black);
+        for col in 0..300 {
+            colorful.set(&Coord(0, col), Color::Rgb(col as u8, (col / 256) as u8, 0));
+        }
+        assert!(matches!(
+            encode_gif(&[colorful], 1.0),
+            Err(ImageError::TooManyColors { found: 300 })
+        ));
+
+        let gif = encode_gif(&[small.clone(), small], 10.0).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
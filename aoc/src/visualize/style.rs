@@ -0,0 +1,314 @@
+use crate::grid_2d::{grid_with_axes, Board, Coord};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::io::IsTerminal;
+
+/// A terminal color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// A 24-bit color. Most modern terminals support these.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// ANSI SGR parameters for this color, where `base` is 30 for the
+    /// foreground or 40 for the background
+    fn sgr(self, base: u8) -> String {
+        let offset = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::Rgb(r, g, b) => return format!("{};2;{};{};{}", base + 8, r, g, b),
+        };
+
+        (base + offset).to_string()
+    }
+
+    /// The red, green and blue components of this color, using xterm's
+    /// default values for the named colors
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+}
+
+/// How to style some text in the terminal
+///
+/// # Examples
+/// ```
+/// use aoc::visualize::{Color, Style};
+///
+/// let style = Style::new().fg(Color::Red).bold();
+///
+/// assert_eq!(style.paint('#'), "\x1b[1;31m#\x1b[0m");
+/// assert_eq!(Style::new().paint('#'), "#");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    /// A plain style, which doesn't change the text at all
+    pub const fn new() -> Self {
+        Style {
+            fg: None,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    /// Set the foreground (text) color
+    pub const fn fg(self, color: Color) -> Self {
+        Style {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    /// Set the background color
+    pub const fn bg(self, color: Color) -> Self {
+        Style {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    /// Make the text bold
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    /// Layer this style on top of another one. Anything set in this style
+    /// takes priority.
+    pub fn over(self, base: Style) -> Style {
+        Style {
+            fg: self.fg.or(base.fg),
+            bg: self.bg.or(base.bg),
+            bold: self.bold || base.bold,
+        }
+    }
+
+    /// Wrap text in the ANSI escape codes for this style
+    pub fn paint<D: Display>(&self, text: D) -> String {
+        let mut codes: Vec<String> = Vec::new();
+
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(fg) = self.fg {
+            codes.push(fg.sgr(30));
+        }
+        if let Some(bg) = self.bg {
+            codes.push(bg.sgr(40));
+        }
+
+        if codes.is_empty() {
+            return text.to_string();
+        }
+
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
+
+/// Whether to print colors by default. Colors are only used if stdout is a
+/// terminal, and the `NO_COLOR` environment variable isn't set.
+pub fn colors_enabled() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Closure deciding the style of each cell on a board
+type StyleFn<'a, T> = Box<dyn Fn(Coord, &T) -> Style + 'a>;
+
+/// Renders a [`Board`] to the terminal, styling each cell.
+///
+/// # Examples
+/// ```no_run
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::visualize::{Color, Renderer, Style};
+///
+/// let board = Board::from_str("#..\n.#.\n..#");
+/// let path = [Coord(0, 1), Coord(0, 2), Coord(1, 2)];
+///
+/// Renderer::new(&board)
+///     .style(|_, c| match c {
+///         '#' => Style::new().fg(Color::Red).bold(),
+///         _ => Style::new(),
+///     })
+///     .overlay(path, Style::new().bg(Color::Blue))
+///     .with_axes()
+///     .print();
+/// ```
+pub struct Renderer<'a, T> {
+    board: &'a Board<T>,
+    style: StyleFn<'a, T>,
+    /// Extra styles for some cells, later overlays going on top
+    overlays: Vec<(HashSet<Coord>, Style)>,
+    axes: bool,
+    color: bool,
+}
+
+impl<'a, T> Renderer<'a, T>
+where
+    T: Display,
+{
+    /// Create a renderer that shows every cell in a plain style. Colors are
+    /// enabled if [`colors_enabled`] says so.
+    pub fn new(board: &'a Board<T>) -> Self {
+        Renderer {
+            board,
+            style: Box::new(|_, _| Style::new()),
+            overlays: Vec::new(),
+            axes: false,
+            color: colors_enabled(),
+        }
+    }
+
+    /// Set how to style each cell
+    pub fn style<F>(mut self, style: F) -> Self
+    where
+        F: Fn(Coord, &T) -> Style + 'a,
+    {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Add a style on top of some cells, such as a path through the board
+    pub fn overlay<I>(mut self, coords: I, style: Style) -> Self
+    where
+        I: IntoIterator<Item = Coord>,
+    {
+        self.overlays.push((coords.into_iter().collect(), style));
+        self
+    }
+
+    /// Show row and column numbers around the board
+    pub fn with_axes(mut self) -> Self {
+        self.axes = true;
+        self
+    }
+
+    /// Force colors on or off, instead of detecting whether they're supported
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Get the final style of a cell, including any overlays
+    pub fn cell_style(&self, c: Coord) -> Style {
+        self.overlays
+            .iter()
+            .filter(|(coords, _)| coords.contains(&c))
+            .fold((self.style)(c, &self.board[c]), |base, (_, style)| {
+                style.over(base)
+            })
+    }
+
+    /// Get the text of a single cell, styled if colors are on
+    fn cell(&self, c: Coord) -> String {
+        if self.color {
+            self.cell_style(c).paint(&self.board[c])
+        } else {
+            self.board[c].to_string()
+        }
+    }
+
+    /// Print the board to the terminal
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+}
+
+impl<T> Display for Renderer<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.axes {
+            return write!(
+                f,
+                "{}",
+                grid_with_axes(Coord(0, 0), self.board.size(), |c| self.cell(c))
+            );
+        }
+
+        for row in 0..self.board.height() {
+            for col in 0..self.board.width() {
+                write!(f, "{}", self.cell((row, col).into()))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_codes() {
+        assert_eq!(Style::new().fg(Color::Green).paint("x"), "\x1b[32mx\x1b[0m");
+        assert_eq!(
+            Style::new().bg(Color::Rgb(1, 2, 3)).bold().paint(5),
+            "\x1b[1;48;2;1;2;3m5\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_overlays() {
+        let board = Board::from_str("ab\ncd");
+        let red = Style::new().fg(Color::Red);
+        let blue_bg = Style::new().bg(Color::Blue);
+
+        let renderer = Renderer::new(&board)
+            .style(move |_, c| if *c == 'a' { red } else { Style::new() })
+            .overlay([Coord(0, 0), Coord(1, 1)], blue_bg)
+            .overlay([Coord(1, 1)], Style::new().fg(Color::Yellow));
+
+        assert_eq!(renderer.cell_style(Coord(0, 0)), red.bg(Color::Blue));
+        assert_eq!(renderer.cell_style(Coord(0, 1)), Style::new());
+        assert_eq!(renderer.cell_style(Coord(1, 1)), blue_bg.fg(Color::Yellow));
+    }
+
+    #[test]
+    fn test_render_without_color() {
+        let board = Board::from_str("ab\ncd");
+        let renderer = Renderer::new(&board)
+            .style(|_, _| Style::new().bold())
+            .with_color(false);
+
+        assert_eq!(renderer.to_string(), "ab\ncd\n");
+        assert_eq!(renderer.with_axes().to_string(), "  01\n0 ab\n1 cd\n");
+
+        let colored = Renderer::new(&board)
+            .with_color(true)
+            .style(|_, _| Style::new().bold());
+        assert_eq!(colored.to_string().matches("\x1b[1m").count(), 4);
+    }
+}
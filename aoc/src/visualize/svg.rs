@@ -0,0 +1,670 @@
+//! Standalone SVG drawings of boards and graphs, for looking at in a browser
+//! or putting in write-ups.
+//!
+//! [`GridSvg`] draws a [`Board`] as a grid of squares, with paths and labelled
+//! markers on top. [`Graph`] lays out nodes and edges in columns, left to
+//! right, following the direction of the edges.
+
+use super::Color;
+use crate::grid_2d::{Board, Coord};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use std::path::Path;
+
+/// Format a color the way SVG expects it
+fn hex(color: Color) -> String {
+    let (r, g, b) = color.rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Escape text so that it can go inside an SVG element or attribute
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Opening tag of an SVG document with the given size
+fn svg_header(width: f64, height: f64) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"monospace\">\n",
+        w = width,
+        h = height
+    )
+}
+
+/// Closure deciding the fill of each cell on a board
+type FillFn<'a, T> = Box<dyn Fn(Coord, &T) -> Option<Color> + 'a>;
+
+/// A line drawn through the centres of some cells
+struct GridPath {
+    coords: Vec<Coord>,
+    color: Color,
+}
+
+/// A labelled dot in the centre of a cell
+struct Marker {
+    coord: Coord,
+    label: String,
+    color: Color,
+}
+
+/// Draws a [`Board`] as an SVG grid of squares.
+///
+/// # Examples
+/// ```no_run
+/// use aoc::grid_2d::{Board, Coord};
+/// use aoc::visualize::svg::GridSvg;
+/// use aoc::visualize::Color;
+///
+/// let board = Board::from_str("S.#\n#..\n..E");
+///
+/// GridSvg::new(&board)
+///     .fill(|_, c| (*c == '#').then_some(Color::Black))
+///     .path([Coord(0, 0), Coord(0, 1), Coord(1, 1), Coord(1, 2), Coord(2, 2)], Color::Red)
+///     .marker(Coord(0, 0), "start", Color::Green)
+///     .marker(Coord(2, 2), "end", Color::Blue)
+///     .save("maze.svg")
+///     .unwrap();
+/// ```
+pub struct GridSvg<'a, T> {
+    board: &'a Board<T>,
+    fill: FillFn<'a, T>,
+    cell_size: f64,
+    grid_lines: bool,
+    paths: Vec<GridPath>,
+    markers: Vec<Marker>,
+}
+
+impl<'a, T> GridSvg<'a, T> {
+    /// Create a drawing of a board, with every cell left empty
+    pub fn new(board: &'a Board<T>) -> Self {
+        GridSvg {
+            board,
+            fill: Box::new(|_, _| None),
+            cell_size: 20.0,
+            grid_lines: true,
+            paths: Vec::new(),
+            markers: Vec::new(),
+        }
+    }
+
+    /// Set the fill color of each cell, or `None` to leave it empty
+    pub fn fill<F>(mut self, fill: F) -> Self
+    where
+        F: Fn(Coord, &T) -> Option<Color> + 'a,
+    {
+        self.fill = Box::new(fill);
+        self
+    }
+
+    /// Set the width and height of each cell. Defaults to 20.
+    pub fn cell_size(mut self, size: f64) -> Self {
+        self.cell_size = size;
+        self
+    }
+
+    /// Turn the lines between cells on or off. They're on by default.
+    pub fn grid_lines(mut self, grid_lines: bool) -> Self {
+        self.grid_lines = grid_lines;
+        self
+    }
+
+    /// Draw a line through the centres of a sequence of cells
+    pub fn path<I>(mut self, coords: I, color: Color) -> Self
+    where
+        I: IntoIterator<Item = Coord>,
+    {
+        self.paths.push(GridPath {
+            coords: coords.into_iter().collect(),
+            color,
+        });
+        self
+    }
+
+    /// Draw a dot in the centre of a cell, with a label next to it
+    pub fn marker(mut self, coord: Coord, label: &str, color: Color) -> Self {
+        self.markers.push(Marker {
+            coord,
+            label: label.to_string(),
+            color,
+        });
+        self
+    }
+
+    /// Centre of a cell, in SVG coordinates
+    fn centre(&self, c: Coord) -> (f64, f64) {
+        (
+            (c.1 as f64 + 0.5) * self.cell_size,
+            (c.0 as f64 + 0.5) * self.cell_size,
+        )
+    }
+
+    /// Save the drawing as an SVG file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl<T> Display for GridSvg<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let size = self.cell_size;
+        let (width, height) = (
+            self.board.width() as f64 * size,
+            self.board.height() as f64 * size,
+        );
+
+        write!(f, "{}", svg_header(width, height))?;
+        writeln!(
+            f,
+            "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>",
+            width, height
+        )?;
+
+        for (c, item) in self.board.iter() {
+            if let Some(color) = (self.fill)(c, item) {
+                writeln!(
+                    f,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    c.1 as f64 * size,
+                    c.0 as f64 * size,
+                    size,
+                    size,
+                    hex(color)
+                )?;
+            }
+        }
+
+        if self.grid_lines {
+            let mut lines = String::new();
+            for row in 0..=self.board.height() {
+                write!(lines, "M0 {}H{}", row as f64 * size, width)?;
+            }
+            for col in 0..=self.board.width() {
+                write!(lines, "M{} 0V{}", col as f64 * size, height)?;
+            }
+            writeln!(
+                f,
+                "<path d=\"{}\" stroke=\"#cccccc\" stroke-width=\"1\" fill=\"none\"/>",
+                lines
+            )?;
+        }
+
+        for path in &self.paths {
+            let points: Vec<String> = path
+                .coords
+                .iter()
+                .map(|c| {
+                    let (x, y) = self.centre(*c);
+                    format!("{},{}", x, y)
+                })
+                .collect();
+
+            writeln!(
+                f,
+                "<polyline points=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"round\" stroke-linejoin=\"round\" stroke-opacity=\"0.8\" fill=\"none\"/>",
+                points.join(" "),
+                hex(path.color),
+                size * 0.3
+            )?;
+        }
+
+        for marker in &self.markers {
+            let (x, y) = self.centre(marker.coord);
+
+            writeln!(
+                f,
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>",
+                x,
+                y,
+                size * 0.35,
+                hex(marker.color)
+            )?;
+            writeln!(
+                f,
+                "<text x=\"{}\" y=\"{}\" font-size=\"{}\" dominant-baseline=\"middle\">{}</text>",
+                x + size * 0.5,
+                y,
+                size * 0.6,
+                escape(&marker.label)
+            )?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Shape of a node in a [`Graph`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Shape {
+    #[default]
+    Rect,
+    Rounded,
+    Ellipse,
+}
+
+/// A node in a [`Graph`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    id: String,
+    label: String,
+    shape: Shape,
+    fill: Color,
+}
+
+impl Node {
+    /// A plain node, labelled with its id
+    pub fn new(id: &str) -> Self {
+        Node {
+            id: id.to_string(),
+            label: id.to_string(),
+            shape: Shape::default(),
+            fill: Color::Rgb(255, 255, 255),
+        }
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn fill(mut self, color: Color) -> Self {
+        self.fill = color;
+        self
+    }
+}
+
+/// A directed graph of labelled nodes, drawn as an SVG.
+///
+/// Nodes are placed in columns so that edges point from left to right, with
+/// each node as far left as its incoming edges allow. Any edges that form a
+/// cycle end up pointing backwards.
+///
+/// # Examples
+/// ```no_run
+/// use aoc::visualize::svg::{Graph, Node, Shape};
+/// use aoc::visualize::Color;
+///
+/// let mut graph = Graph::new();
+///
+/// graph.add_node(Node::new("x").shape(Shape::Rounded));
+/// graph.add_node(Node::new("y").shape(Shape::Rounded));
+/// graph.add_node(Node::new("z").label("x AND y -> z").fill(Color::Yellow));
+/// graph.add_edge("x", "z");
+/// graph.add_edge("y", "z");
+///
+/// graph.save("graph.svg").unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+/// Height of a node
+const NODE_HEIGHT: f64 = 30.0;
+/// Space between nodes in the same column
+const NODE_GAP: f64 = 20.0;
+/// Space between columns
+const LAYER_GAP: f64 = 60.0;
+/// Rough width of a character of the label
+const CHAR_WIDTH: f64 = 8.0;
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node, replacing any existing node with the same id
+    pub fn add_node(&mut self, node: Node) {
+        match self.index.get(&node.id) {
+            Some(&i) => self.nodes[i] = node,
+            None => {
+                self.index.insert(node.id.clone(), self.nodes.len());
+                self.nodes.push(node);
+            }
+        }
+    }
+
+    /// Add an edge between two nodes. Nodes that haven't been added yet are
+    /// added as plain nodes.
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let from = self.node_index(from);
+        let to = self.node_index(to);
+
+        self.edges.push((from, to));
+    }
+
+    fn node_index(&mut self, id: &str) -> usize {
+        if !self.index.contains_key(id) {
+            self.add_node(Node::new(id));
+        }
+
+        self.index[id]
+    }
+
+    /// Decide which column each node is in. Returns the column of each node,
+    /// and whether each edge is part of a cycle (and so ignored).
+    fn layers(&self) -> (Vec<usize>, Vec<bool>) {
+        let n = self.nodes.len();
+        let mut outgoing: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+        for (e, &(from, to)) in self.edges.iter().enumerate() {
+            outgoing[from].push((e, to));
+        }
+
+        // Depth first search, finding the edges that loop back to a node
+        // that's still being visited, and a topological order of the rest
+        #[derive(Clone, Copy, PartialEq)]
+        enum Visit {
+            New,
+            Active,
+            Done,
+        }
+
+        let mut visits = vec![Visit::New; n];
+        let mut back_edges = vec![false; self.edges.len()];
+        let mut order = Vec::with_capacity(n);
+
+        for root in 0..n {
+            if visits[root] != Visit::New {
+                continue;
+            }
+
+            visits[root] = Visit::Active;
+            let mut stack = vec![(root, 0)];
+
+            while let Some((node, next)) = stack.last_mut() {
+                let node = *node;
+
+                let Some(&(e, to)) = outgoing[node].get(*next) else {
+                    visits[node] = Visit::Done;
+                    order.push(node);
+                    stack.pop();
+                    continue;
+                };
+                *next += 1;
+
+                match visits[to] {
+                    Visit::New => {
+                        visits[to] = Visit::Active;
+                        stack.push((to, 0));
+                    }
+                    Visit::Active => back_edges[e] = true,
+                    Visit::Done => {}
+                }
+            }
+        }
+
+        // Longest path from any source, going through nodes in topological
+        // order
+        let mut layers = vec![0; n];
+        for &node in order.iter().rev() {
+            for &(e, to) in &outgoing[node] {
+                if !back_edges[e] {
+                    layers[to] = layers[to].max(layers[node] + 1);
+                }
+            }
+        }
+
+        (layers, back_edges)
+    }
+
+    /// Position the centre of every node, returning the positions and the
+    /// size of the whole drawing
+    fn layout(&self, node_width: f64) -> (Vec<(f64, f64)>, (f64, f64)) {
+        let (layers, back_edges) = self.layers();
+        let layer_count = layers.iter().max().map_or(0, |l| l + 1);
+
+        let mut columns: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+        for (node, &layer) in layers.iter().enumerate() {
+            columns[layer].push(node);
+        }
+
+        // Reduce crossings by repeatedly sorting each column by the average
+        // position of its neighbours in the column before, then after
+        let mut rank = vec![0.0; self.nodes.len()];
+        let update_ranks = |columns: &Vec<Vec<usize>>, rank: &mut Vec<f64>| {
+            for column in columns {
+                for (i, &node) in column.iter().enumerate() {
+                    rank[node] = i as f64;
+                }
+            }
+        };
+        update_ranks(&columns, &mut rank);
+
+        for sweep in 0..4 {
+            let forwards = sweep % 2 == 0;
+
+            let order: Vec<usize> = if forwards {
+                (1..layer_count).collect()
+            } else {
+                (0..layer_count.saturating_sub(1)).rev().collect()
+            };
+
+            for layer in order {
+                let mut sums: HashMap<usize, (f64, usize)> = HashMap::new();
+
+                for (e, &(from, to)) in self.edges.iter().enumerate() {
+                    if back_edges[e] {
+                        continue;
+                    }
+                    let (node, neighbour) = if forwards { (to, from) } else { (from, to) };
+
+                    if layers[node] == layer {
+                        let entry = sums.entry(node).or_insert((0.0, 0));
+                        entry.0 += rank[neighbour];
+                        entry.1 += 1;
+                    }
+                }
+
+                // Nodes without any neighbours stay where they are
+                let key = |node: &usize| match sums.get(node) {
+                    Some((sum, count)) => sum / *count as f64,
+                    None => rank[*node],
+                };
+                columns[layer].sort_by(|a, b| key(a).total_cmp(&key(b)));
+                update_ranks(&columns, &mut rank);
+            }
+        }
+
+        let tallest = columns.iter().map(Vec::len).max().unwrap_or(0) as f64;
+        let height = tallest * (NODE_HEIGHT + NODE_GAP) + NODE_GAP;
+        let width = layer_count as f64 * (node_width + LAYER_GAP);
+
+        let mut positions = vec![(0.0, 0.0); self.nodes.len()];
+        for (layer, column) in columns.iter().enumerate() {
+            // Centre each column vertically
+            let offset = (tallest - column.len() as f64) * (NODE_HEIGHT + NODE_GAP) / 2.0;
+
+            for (i, &node) in column.iter().enumerate() {
+                positions[node] = (
+                    LAYER_GAP / 2.0 + layer as f64 * (node_width + LAYER_GAP) + node_width / 2.0,
+                    offset + NODE_GAP + i as f64 * (NODE_HEIGHT + NODE_GAP) + NODE_HEIGHT / 2.0,
+                );
+            }
+        }
+
+        (positions, (width, height))
+    }
+
+    /// Save the graph as an SVG file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl Display for Graph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let longest_label = self
+            .nodes
+            .iter()
+            .map(|n| n.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let node_width = longest_label as f64 * CHAR_WIDTH + 20.0;
+        let (positions, (width, height)) = self.layout(node_width);
+
+        write!(f, "{}", svg_header(width, height))?;
+        writeln!(
+            f,
+            "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto\"><path d=\"M0 0L10 5L0 10z\" fill=\"#555555\"/></marker></defs>"
+        )?;
+        writeln!(
+            f,
+            "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>",
+            width, height
+        )?;
+
+        // Edges go from the right of one node to the left of the other
+        for &(from, to) in &self.edges {
+            let (x1, y1) = (positions[from].0 + node_width / 2.0, positions[from].1);
+            let (x2, y2) = (positions[to].0 - node_width / 2.0, positions[to].1);
+            // Edges in a cycle point backwards, so keep their curves small to
+            // stay inside the drawing
+            let bend = ((x2 - x1) / 2.0).max(LAYER_GAP / 2.0);
+
+            writeln!(
+                f,
+                "<path d=\"M{} {}C{} {} {} {} {} {}\" stroke=\"#555555\" fill=\"none\" marker-end=\"url(#arrow)\"/>",
+                x1,
+                y1,
+                x1 + bend,
+                y1,
+                x2 - bend,
+                y2,
+                x2,
+                y2
+            )?;
+        }
+
+        for (node, &(x, y)) in self.nodes.iter().zip(&positions) {
+            let fill = hex(node.fill);
+            let style = format!("fill=\"{}\" stroke=\"#333333\"", fill);
+
+            match node.shape {
+                Shape::Rect | Shape::Rounded => {
+                    let radius = if node.shape == Shape::Rounded {
+                        NODE_HEIGHT / 2.0
+                    } else {
+                        0.0
+                    };
+
+                    writeln!(
+                        f,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" {}/>",
+                        x - node_width / 2.0,
+                        y - NODE_HEIGHT / 2.0,
+                        node_width,
+                        NODE_HEIGHT,
+                        radius,
+                        style
+                    )?;
+                }
+                Shape::Ellipse => writeln!(
+                    f,
+                    "<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\" {}/>",
+                    x,
+                    y,
+                    node_width / 2.0,
+                    NODE_HEIGHT / 2.0,
+                    style
+                )?,
+            }
+
+            writeln!(
+                f,
+                "<text x=\"{}\" y=\"{}\" font-size=\"13\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>",
+                x,
+                y,
+                escape(&node.label)
+            )?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("a < b && \"c\""),
+            "a &lt; b &amp;&amp; &quot;c&quot;"
+        );
+        assert_eq!(hex(Color::Rgb(255, 0, 16)), "#ff0010");
+    }
+
+    #[test]
+    fn test_grid_svg() {
+        let board = Board::from_str("#.\n.#");
+
+        let svg = GridSvg::new(&board)
+            .fill(|_, c| (*c == '#').then_some(Color::Black))
+            .cell_size(10.0)
+            .path([Coord(0, 1), Coord(1, 1)], Color::Red)
+            .marker(Coord(1, 0), "<start>", Color::Green)
+            .to_string();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"20\" height=\"20\""));
+        assert_eq!(svg.matches("fill=\"#000000\"").count(), 2);
+        assert!(svg.contains("points=\"15,5 15,15\""));
+        assert!(svg.contains("&lt;start&gt;"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_graph_layers() {
+        let mut graph = Graph::new();
+
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "c");
+        graph.add_edge("a", "c");
+        graph.add_edge("c", "a");
+        graph.add_edge("d", "c");
+        graph.add_node(Node::new("b").label("bee"));
+
+        let (layers, back_edges) = graph.layers();
+
+        assert_eq!(layers, vec![0, 1, 2, 0]);
+        assert_eq!(back_edges, vec![false, false, false, true, false]);
+        assert_eq!(graph.nodes[1].label, "bee");
+        assert_eq!(graph.to_string().matches("marker-end").count(), 5);
+    }
+
+    #[test]
+    fn test_graph_ordering() {
+        // Without reordering, the edges into the second column would cross
+        let mut graph = Graph::new();
+
+        graph.add_node(Node::new("x"));
+        graph.add_edge("a", "y");
+        graph.add_edge("b", "x");
+
+        let (positions, _) = graph.layout(30.0);
+        let position = |id: &str| positions[graph.index[id]];
+
+        assert!(position("a").1 < position("b").1);
+        assert!(position("y").1 < position("x").1);
+        assert!(position("a").0 < position("y").0);
+    }
+}
@@ -1,35 +1,24 @@
 use aoc::grid_2d::{Board, Coord, Dir};
+use aoc::grid_cell;
 use std::collections::HashSet;
 
-#[derive(Debug, Clone)]
-enum Cell {
-    Empty,
-    Occupied,
+grid_cell! {
+    #[derive(Debug, Clone)]
+    pub enum Cell {
+        '.' => Empty,
+        '#' => Occupied,
+    }
 }
 
-pub fn solution(input: &str) -> usize {
-    let mut position: Coord = Coord(0, 0);
+/// Parse the input into the board, and the guard's starting position
+pub fn parse_input(input: &str) -> (Board<Cell>, Coord) {
+    let (board, markers) = Board::parse_with_markers(input, &['^'], Cell::Empty).unwrap();
 
-    let board: Vec<Vec<Cell>> = input
-        .lines()
-        .enumerate()
-        .map(|(row, l)| {
-            l.chars()
-                .enumerate()
-                .map(|(col, c)| match c {
-                    '.' => Cell::Empty,
-                    '#' => Cell::Occupied,
-                    '^' => {
-                        position = Coord(row as i32, col as i32);
-                        Cell::Empty
-                    }
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect();
+    (board, markers[&'^'])
+}
 
-    let board: Board<Cell> = Board::new(board);
+pub fn solution(input: &str) -> usize {
+    let (board, mut position) = parse_input(input);
 
     let mut dir = Dir::North;
     let mut visited: HashSet<Coord> = HashSet::new();
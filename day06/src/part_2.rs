@@ -1,10 +1,6 @@
+use crate::part_1::{parse_input, Cell};
 use aoc::grid_2d::{Board, Coord, Dir};
 use std::collections::HashSet;
-#[derive(Debug, Clone)]
-enum Cell {
-    Empty,
-    Occupied,
-}
 
 #[derive(Debug)]
 enum Outcome {
@@ -45,28 +41,7 @@ fn walk_around(
 }
 
 pub fn solution(input: &str) -> usize {
-    let mut starting_position: Coord = Coord(0, 0);
-
-    let board: Vec<Vec<Cell>> = input
-        .lines()
-        .enumerate()
-        .map(|(row, l)| {
-            l.chars()
-                .enumerate()
-                .map(|(col, c)| match c {
-                    '.' => Cell::Empty,
-                    '#' => Cell::Occupied,
-                    '^' => {
-                        starting_position = Coord(row as i32, col as i32);
-                        Cell::Empty
-                    }
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect();
-
-    let mut board: Board<Cell> = Board::new(board);
+    let (mut board, starting_position) = parse_input(input);
     let mut num_loops = 0;
 
     // Optimization: Instead of trying every position, we only need to try introducing
@@ -13,18 +13,10 @@ pub fn solution(input: &str) -> usize {
             let diff = (b - a).simplify();
 
             // Walk backwards from a (including a itself) to find antinodes in that direction
-            let mut coord: Coord = *a;
-            while board.get(&coord).is_some() {
-                antinode_positions.insert(coord);
-                coord = coord - diff;
-            }
+            antinode_positions.extend(board.line(*a, diff * -1).map(|(coord, _)| coord));
 
             // Now walk forwards to find antinodes in the other direction
-            coord = *a + diff;
-            while board.get(&coord).is_some() {
-                antinode_positions.insert(coord);
-                coord = coord + diff;
-            }
+            antinode_positions.extend(board.line(*a, diff).map(|(coord, _)| coord));
         }
     }
 
@@ -13,7 +13,7 @@ pub fn solution(input: &str) -> usize {
             let mut queue = vec![start];
 
             while let Some(current) = queue.pop() {
-                let Some(current_height) = board.get(&current) else {
+                let Some(&current_height) = board.get(&current) else {
                     // Off the board
                     continue;
                 };
@@ -26,7 +26,7 @@ pub fn solution(input: &str) -> usize {
 
                 for dir in Dir::cardinal() {
                     let next = current + dir;
-                    let Some(next_height) = board.get(&next) else {
+                    let Some(&next_height) = board.get(&next) else {
                         continue;
                     };
 
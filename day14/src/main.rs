@@ -2,7 +2,9 @@ mod part_1;
 mod part_2;
 
 fn main() {
-    // Usage: cargo run -- <part> <input|example>
+    // Usage: cargo run -- <part> <input|example> [watch]
+    // Watching is only for part 2. It steps through the robots' positions, and
+    // saves the frame it's quit on to tree.png
     let args: Vec<String> = std::env::args().collect();
     let part = args.get(1).expect("No part provided");
     let input = args.get(2).expect("No input file provided").clone() + ".txt";
@@ -15,6 +17,18 @@ fn main() {
 
     let input = std::fs::read_to_string(input).expect("Failed to read input file");
 
+    if args.get(3).is_some_and(|arg| arg == "watch") {
+        let res = match part.as_str() {
+            "1" => panic!("Only part 2 can be watched"),
+            "2" => part_2::watch(&input, board_size),
+            _ => panic!("Invalid part provided"),
+        };
+        if let Err(error) = res {
+            eprintln!("{}", error);
+        }
+        return;
+    }
+
     let res = match part.as_str() {
         "1" => part_1::solution(&input, board_size),
         "2" => part_2::solution(&input, board_size),
@@ -1,4 +1,4 @@
-use aoc::grid_2d::Coord;
+use aoc::grid_2d::{Board, Coord, Rect, Topology};
 use itertools::Itertools;
 
 /// Parse the input into pairs of (start position, velocity) vectors
@@ -22,35 +22,28 @@ pub fn parse_input(input: &str) -> Vec<(Coord, Coord)> {
 }
 
 pub fn solution(input: &str, board_size: (i32, i32)) -> usize {
-    let midpoints = (board_size.0 / 2, board_size.1 / 2);
+    let quadrants = Rect::from_size(board_size)
+        .and_then(|board| board.quadrants())
+        .unwrap();
 
-    let end_positions = parse_input(input).into_iter().map(|(position, velocity)| {
-        let end = position + (velocity * 100);
+    // Robots teleport to the opposite edge when they leave the board
+    let board = Board::from_size(board_size, '.').with_topology(Topology::Toroidal);
 
-        // Wrap the end position so that it's back on the board
-        end.wrap_to_size(board_size)
-    });
+    let end_positions: Vec<Coord> = parse_input(input)
+        .into_iter()
+        .map(|(position, velocity)| board.normalize(&(position + (velocity * 100))).unwrap())
+        .collect();
 
-    let mut quadrant_counts = [0usize; 4];
-
-    for coord in end_positions {
-        #[allow(clippy::comparison_chain)]
-        if coord.0 < midpoints.0 {
-            if coord.1 < midpoints.1 {
-                quadrant_counts[0] += 1;
-            } else if coord.1 > midpoints.1 {
-                quadrant_counts[1] += 1;
-            }
-        } else if coord.0 > midpoints.0 {
-            if coord.1 < midpoints.1 {
-                quadrant_counts[2] += 1;
-            } else if coord.1 > midpoints.1 {
-                quadrant_counts[3] += 1;
-            }
-        }
-    }
-
-    quadrant_counts.iter().product::<usize>()
+    // Robots on the middle row or column aren't in any quadrant
+    quadrants
+        .iter()
+        .map(|quadrant| {
+            end_positions
+                .iter()
+                .filter(|c| quadrant.contains(c))
+                .count()
+        })
+        .product()
 }
 
 #[cfg(test)]
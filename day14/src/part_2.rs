@@ -1,67 +1,40 @@
-use std::collections::HashSet;
-
 use crate::part_1::parse_input;
-use aoc::grid_2d::{Board, Coord};
-use aoc::visualize::prompt;
+use aoc::grid_2d::{Board, Connectivity, Coord, Region, Topology};
+use aoc::visualize::{Animation, Color, Image, ImageError, Renderer, Style};
 
-fn step(robots: &mut Vec<(Coord, Coord)>, board_size: (i32, i32)) {
+fn step(robots: &mut [(Coord, Coord)], board: &Board<char>) {
     for (position, velocity) in robots.iter_mut() {
-        *position = (*position + *velocity).wrap_to_size(board_size);
+        *position = board.normalize(&(*position + *velocity)).unwrap();
     }
 }
 
 /// Find the size of the largest contiguous region of non-empty cells
 fn get_largest_region_size(board: &Board<char>) -> usize {
-    // Very similar to day 12
-    let mut visited: HashSet<Coord> = HashSet::new();
-
-    let mut res = 0;
-
-    for start in board.positions() {
-        if visited.contains(&start) {
-            continue;
-        }
-
-        if board.get(&start) != Some('#') {
-            continue;
-        }
-
-        let mut region_size = 0;
-        let mut queue: Vec<Coord> = vec![start];
-
-        while let Some(position) = queue.pop() {
-            if visited.contains(&position) {
-                continue;
-            }
-
-            if board.get(&position) != Some('#') {
-                continue;
-            }
-
-            visited.insert(position);
-            region_size += 1;
-
-            let neighbours = position.cardinal_neighbours();
-            queue.extend(neighbours);
-        }
-
-        res = res.max(region_size);
-    }
-
-    res
+    board
+        .regions_where(Connectivity::Four, |c| *c == '#')
+        .iter()
+        .map(Region::area)
+        .max()
+        .unwrap_or(0)
 }
 
-pub fn solution(input: &str, board_size: (i32, i32)) -> usize {
+/// Move the robots around, calling `on_growth` with the iteration, the size of
+/// the largest region and the board whenever the largest region grows
+fn growing_regions<F>(input: &str, board_size: (i32, i32), mut on_growth: F)
+where
+    F: FnMut(usize, usize, &Board<char>),
+{
     let mut robots = parse_input(input);
 
-    let mut board = Board::from_size(board_size, '.');
+    // Robots teleport to the opposite edge when they leave the board
+    let mut board = Board::from_size(board_size, '.').with_topology(Topology::Toroidal);
 
-    let mut iterations = 0;
     let mut largest_region = 0;
 
-    loop {
-        iterations += 1;
-        step(&mut robots, board_size);
+    // Each robot comes back to where it started after width * height steps, so
+    // everything after that is a repeat
+    for iterations in 1..=(board_size.0 * board_size.1) as usize {
+        step(&mut robots, &board);
 
         for (position, _) in robots.iter() {
             board.set(position, '#');
@@ -70,17 +43,8 @@ pub fn solution(input: &str, board_size: (i32, i32)) -> usize {
         let this_largest_region = get_largest_region_size(&board);
 
         if this_largest_region > largest_region {
-            board.print();
             largest_region = this_largest_region;
-
-            let t = prompt(&format!(
-                "Iteration {}. Largest region {}. Type q to quit.",
-                iterations, largest_region
-            ));
-
-            if t.as_str() == "q" {
-                break;
-            }
+            on_growth(iterations, largest_region, &board);
         }
 
         // Reset the board
@@ -88,11 +52,65 @@ pub fn solution(input: &str, board_size: (i32, i32)) -> usize {
             board.set(position, '.');
         }
     }
+}
+
+/// The robots clump together to draw the Christmas tree, so it's on the
+/// iteration with the largest region of robots
+pub fn solution(input: &str, board_size: (i32, i32)) -> usize {
+    let mut tree = None;
+    growing_regions(input, board_size, |iterations, _, _| {
+        tree = Some(iterations)
+    });
 
-    iterations
+    tree.expect("No robots found")
 }
 
-// No unit tests for this one because it's done by manual inspection.
-// On my input, the Christmas tree occurs after 8179 iterations.
-// That takes 7 outputs of the above largest region printout, and about
-// 30s.
+/// Play an animation of every board where the largest region grew, and save
+/// the one on screen when quitting to `tree.png`
+pub fn watch(input: &str, board_size: (i32, i32)) -> Result<(), ImageError> {
+    let mut candidates = Vec::new();
+    growing_regions(input, board_size, |iterations, largest_region, board| {
+        candidates.push((iterations, board.clone(), largest_region))
+    });
+
+    let mut animation = Animation::new().with_fps(1.0);
+
+    for (iterations, board, largest_region) in candidates.iter() {
+        let renderer = Renderer::new(board).style(|_, c| match c {
+            '#' => Style::new().fg(Color::Green).bold(),
+            _ => Style::new(),
+        });
+        animation.push(format!(
+            "{}Iteration {}. Largest region {}.",
+            renderer, iterations, largest_region
+        ));
+    }
+
+    // Quit on the frame with the tree in it
+    let Some(frame) = animation.play() else {
+        return Ok(());
+    };
+    let (iterations, board, _) = &candidates[frame];
+
+    let image = Image::from_board(board, 4, |_, c| match c {
+        '#' => Color::Green,
+        _ => Color::Black,
+    });
+    image.save("tree.png")?;
+    println!("Saved iteration {} to tree.png", iterations);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = include_str!("../input.txt");
+        let res = solution(input, (101, 103));
+
+        assert_eq!(res, 8179);
+    }
+}
@@ -2,13 +2,23 @@ mod part_1;
 mod part_2;
 
 fn main() {
-    // Usage: cargo run -- <part> <input|example>
+    // Usage: cargo run -- <part> <input|example> [watch]
+    // Watching shows the robot moving around that part's warehouse
     let args: Vec<String> = std::env::args().collect();
     let part = args.get(1).expect("No part provided");
     let input = args.get(2).expect("No input file provided").clone() + ".txt";
 
     let input = std::fs::read_to_string(input).expect("Failed to read input file");
 
+    if args.get(3).is_some_and(|arg| arg == "watch") {
+        match part.as_str() {
+            "1" => part_1::watch(&input),
+            "2" => part_2::watch(&input),
+            _ => panic!("Invalid part provided"),
+        }
+        return;
+    }
+
     let res = match part.as_str() {
         "1" => part_1::solution(&input),
         "2" => part_2::solution(&input),
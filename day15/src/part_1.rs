@@ -1,4 +1,5 @@
 use aoc::grid_2d::{Board, Coord, Dir};
+use aoc::visualize::{Animation, Color, Renderer, Style};
 
 pub fn parse_directions(directions: &str) -> Vec<Dir> {
     directions
@@ -51,14 +52,19 @@ pub fn run_step(board: &mut Board<char>, robot: Coord, dir: Dir) -> Coord {
     robot
 }
 
+/// Parse the warehouse map. Returns the board, and the robot's position.
+pub fn parse_warehouse(input: &str) -> (Board<char>, Coord) {
+    let (board, markers) = Board::parse_with_markers(input, &['@'], '@').unwrap();
+
+    (board, markers[&'@'])
+}
+
 pub fn solution(input: &str) -> i32 {
     let (board, directions) = input.split_once("\n\n").unwrap();
-    let mut board = Board::from_str(board);
+    let (mut board, mut robot) = parse_warehouse(board);
 
     let directions: Vec<Dir> = parse_directions(directions);
 
-    let mut robot = board.find(&'@')[0];
-
     for dir in directions {
         robot = run_step(&mut board, robot, dir);
     }
@@ -74,6 +80,53 @@ pub fn solution(input: &str) -> i32 {
         .sum()
 }
 
+/// Play an animation of the robot moving around the warehouse
+pub fn watch(input: &str) {
+    watch_with(input, parse_warehouse, run_step, &['O']);
+}
+
+/// Most frames to show when watching. Longer inputs only show every few moves,
+/// so they don't take forever to play or fill up memory.
+const MAX_FRAMES: usize = 600;
+
+/// Play an animation of the robot moving around a warehouse, which is read
+/// with `parse` and moved around with `step`. Cells with any of the `boxes`
+/// characters are highlighted.
+pub fn watch_with<P, S>(input: &str, parse: P, step: S, boxes: &[char])
+where
+    P: Fn(&str) -> (Board<char>, Coord),
+    S: Fn(&mut Board<char>, Coord, Dir) -> Coord,
+{
+    let (board, directions) = input.split_once("\n\n").unwrap();
+    let (mut board, mut robot) = parse(board);
+
+    let directions: Vec<Dir> = parse_directions(directions);
+
+    let moves = directions.len();
+    let stride = moves.div_ceil(MAX_FRAMES).max(1);
+    let mut animation = Animation::new().with_fps(20.0);
+
+    for i in 0..=moves {
+        if i > 0 {
+            robot = step(&mut board, robot, directions[i - 1]);
+        }
+
+        // Always show the start and the end
+        if i % stride != 0 && i != moves {
+            continue;
+        }
+
+        let renderer = Renderer::new(&board).style(|_, c| match c {
+            '@' => Style::new().fg(Color::Red).bold(),
+            c if boxes.contains(c) => Style::new().fg(Color::Yellow),
+            _ => Style::new(),
+        });
+        animation.push(format!("{}Move {}/{}", renderer, i, moves));
+    }
+
+    animation.play();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,7 +33,7 @@ pub fn run_step_horizontal(board: &mut Board<char>, robot: Coord, dir: Dir) -> C
     let backwards = dir.rotate_180();
     let mut to_shift = next + backwards;
 
-    while let Some(c) = board.get(&to_shift) {
+    while let Some(&c) = board.get(&to_shift) {
         match c {
             '[' | ']' => {
                 // A box to move over
@@ -122,7 +122,7 @@ fn run_step_vertical(board: &mut Board<char>, robot: Coord, dir: Dir) -> Coord {
 
     for coord in to_move {
         // Move in the direction, replace with a .
-        let element = board.get(&coord).unwrap();
+        let element = board[coord];
 
         board.set(&(coord + dir), element);
         board.set(&coord, '.');
@@ -132,25 +132,29 @@ fn run_step_vertical(board: &mut Board<char>, robot: Coord, dir: Dir) -> Coord {
     robot + dir
 }
 
-pub fn solution(input: &str) -> i32 {
-    let (board, directions) = input.split_once("\n\n").unwrap();
+/// Simulate the robot's movement in `dir` direction, on the wide board.
+///
+/// Returns the new position of the robot, and updates the board in-place.
+pub fn run_step(board: &mut Board<char>, robot: Coord, dir: Dir) -> Coord {
+    match dir {
+        Dir::East | Dir::West => run_step_horizontal(board, robot, dir),
+        Dir::North | Dir::South => run_step_vertical(board, robot, dir),
+        _ => panic!("Non-cardinal direction {:?}", dir),
+    }
+}
 
-    // Construct a board that's twice as wide.
-    let matrix: Vec<Vec<char>> = board
+/// Parse the warehouse map, making it twice as wide. Returns the board, and
+/// the robot's position.
+pub fn parse_wide_warehouse(input: &str) -> (Board<char>, Coord) {
+    let wide: String = input
         .lines()
         .map(|line| {
-            let mut row = Vec::new();
+            let mut row = String::new();
 
             for c in line.chars() {
                 match c {
-                    '@' => {
-                        row.push('@');
-                        row.push('.');
-                    }
-                    'O' => {
-                        row.push('[');
-                        row.push(']');
-                    }
+                    '@' => row.push_str("@."),
+                    'O' => row.push_str("[]"),
                     '.' | '#' => {
                         row.push(c);
                         row.push(c);
@@ -161,24 +165,20 @@ pub fn solution(input: &str) -> i32 {
 
             row
         })
-        .collect();
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    part_1::parse_warehouse(&wide)
+}
 
-    let mut board = Board::new(matrix);
+pub fn solution(input: &str) -> i32 {
+    let (board, directions) = input.split_once("\n\n").unwrap();
+    let (mut board, mut robot) = parse_wide_warehouse(board);
 
     let directions: Vec<Dir> = part_1::parse_directions(directions);
 
-    let mut robot = board.find(&'@')[0];
-
     for dir in directions {
-        match dir {
-            Dir::East | Dir::West => {
-                robot = run_step_horizontal(&mut board, robot, dir);
-            }
-            Dir::North | Dir::South => {
-                robot = run_step_vertical(&mut board, robot, dir);
-            }
-            _ => panic!("Non-cardinal direction {:?}", dir),
-        }
+        robot = run_step(&mut board, robot, dir);
     }
 
     // Sum up the coordinates of the boxes
@@ -192,6 +192,11 @@ pub fn solution(input: &str) -> i32 {
         .sum()
 }
 
+/// Play an animation of the robot moving around the wide warehouse
+pub fn watch(input: &str) {
+    part_1::watch_with(input, parse_wide_warehouse, run_step, &['[', ']']);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
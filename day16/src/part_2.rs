@@ -1,52 +1,20 @@
-use crate::part_1::{parse_input, run_astar, Cell, State};
+use crate::part_1::{parse_input, run_astar};
 use aoc::grid_2d::Coord;
 use std::collections::HashSet;
 
 pub fn solution(input: &str) -> u32 {
-    let board = parse_input(input);
+    let (board, start, end) = parse_input(input);
 
-    let (_cost, parents) = run_astar(&board).expect("No solution found");
+    let shortest_paths = run_astar(&board, start, end).expect("No solution found");
 
-    // `parents` maps us from state -> states we got there from.
-    // Traverse our way back from end to start to find all paths.
-    let mut paths: Vec<Vec<State>> = Vec::new();
-
-    let start = board.find(&Cell::Start)[0];
-    let end = board.find(&Cell::End)[0];
-
-    let mut partial_paths: Vec<Vec<State>> = parents
-        .iter()
-        // Starting with just a single path containing the end state
-        .filter(|(state, _)| state.position == end)
-        .map(|(state, _)| vec![state.clone()])
+    // Tiles on any of the best paths, whichever way they were facing
+    let tiles: HashSet<Coord> = shortest_paths
+        .states()
+        .into_iter()
+        .map(|state| state.position)
         .collect();
 
-    let mut visited: HashSet<Coord> = HashSet::new();
-
-    while let Some(mut path) = partial_paths.pop() {
-        let state = path.last().unwrap();
-        visited.insert(state.position);
-
-        if state.position == start {
-            // Found a complete path
-            path.reverse();
-            // Not being used in this solution, but it's here to
-            // copy in the future
-            paths.push(path);
-            continue;
-        }
-
-        if let Some(parents_set) = parents.get(state) {
-            for parent in parents_set.iter() {
-                // For each parent, a new path diverges
-                let mut new_path = path.clone();
-                new_path.push(parent.clone());
-                partial_paths.push(new_path);
-            }
-        }
-    }
-
-    visited.len() as u32
+    tiles.len() as u32
 }
 
 #[cfg(test)]
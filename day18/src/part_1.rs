@@ -1,43 +1,14 @@
 use aoc::grid_2d::{Board, Coord};
+use aoc::grid_cell;
+use aoc::search::astar;
 use itertools::Itertools;
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Clone)]
-pub enum Cell {
-    Empty,
-    Occupied,
-    Visited,
-}
-
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Cell::Empty => write!(f, "."),
-            Cell::Occupied => write!(f, "#"),
-            Cell::Visited => write!(f, "X"),
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq)]
-pub struct Visit {
-    state: Coord,
-    current_cost: u32,
-    estimated_total_cost: u32,
-}
-
-impl Ord for Visit {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Reversed ordering to make this a min-heap
-        other.estimated_total_cost.cmp(&self.estimated_total_cost)
-    }
-}
-
-impl PartialOrd for Visit {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+grid_cell! {
+    #[derive(Debug, Clone)]
+    pub enum Cell {
+        '.' => Empty,
+        '#' => Occupied,
+        'X' => Visited,
     }
 }
 
@@ -45,56 +16,20 @@ impl PartialOrd for Visit {
 ///
 /// Returns the shortest number of steps.
 pub fn run_astar(board: &Board<Cell>, start: Coord, target: Coord) -> Option<u32> {
-    let mut costs: HashMap<Coord, u32> = HashMap::new();
-    let mut visited: HashSet<Coord> = HashSet::new();
-    let mut to_visit: BinaryHeap<Visit> = BinaryHeap::new();
-
-    to_visit.push(Visit {
-        state: start,
-        current_cost: 0,
-        estimated_total_cost: 0,
-    });
-
-    while let Some(Visit {
-        state,
-        current_cost,
-        ..
-    }) = to_visit.pop()
-    {
-        if matches!(board.get(&state), None | Some(Cell::Occupied)) {
-            // Can't move here
-            continue;
-        }
-
-        if !visited.insert(state) {
-            // Already been here
-            continue;
-        }
-
-        if state == target {
-            return Some(current_cost);
-        }
-
-        for new_position in state.cardinal_neighbours() {
-            let heuristic_cost = new_position.manhattan_distance(&target);
-            let new_cost = current_cost + 1;
-
-            let is_cheaper = costs
-                .get(&new_position)
-                .map_or(true, |&current| new_cost < current);
-
-            if is_cheaper {
-                costs.insert(new_position.clone(), new_cost);
-                to_visit.push(Visit {
-                    state: new_position,
-                    current_cost: new_cost,
-                    estimated_total_cost: new_cost + heuristic_cost,
-                });
-            }
-        }
-    }
-
-    None
+    astar(
+        start,
+        |&position| {
+            position
+                .cardinal_neighbours()
+                .into_iter()
+                // Can't move off the board or onto a fallen byte
+                .filter(move |n| !matches!(board.get(n), None | Some(Cell::Occupied)))
+                .map(|n| (n, 1))
+        },
+        |position| position.manhattan_distance(&target),
+        |position| *position == target,
+    )
+    .map(|path| path.cost)
 }
 
 pub fn solution(input: &str, board_size: (usize, usize), initial_drop: usize) -> u32 {
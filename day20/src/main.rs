@@ -1,14 +1,26 @@
+use aoc::visualize::Heatmap;
+
 mod part_1;
 mod part_2;
 
 fn main() {
-    // Usage: cargo run -- <part> <input|example>
+    // Usage: cargo run -- <part> <input|example> [heatmap]
+    // The heatmap shows how far each part of the track is from the end
     let args: Vec<String> = std::env::args().collect();
     let part = args.get(1).expect("No part provided");
     let input = args.get(2).expect("No input file provided").clone() + ".txt";
 
     let input = std::fs::read_to_string(input).expect("Failed to read input file");
 
+    if args.get(3).is_some_and(|arg| arg == "heatmap") {
+        let (board, end) = part_1::parse_input(&input);
+        let distances = board.distance_map([end], |c| *c != '#');
+        Heatmap::from_board_with(&distances, |d| d.map(f64::from))
+            .half_blocks()
+            .print();
+        return;
+    }
+
     let res = match part.as_str() {
         "1" => part_1::solution(&input),
         "2" => part_2::solution(&input),
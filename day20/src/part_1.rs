@@ -1,44 +1,20 @@
 use aoc::grid_2d::{Board, Coord, Dir};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-/// Explore the track. Returns a map from position -> how far away the
-/// end is.
-pub fn explore_track(board: &Board<char>) -> HashMap<Coord, usize> {
-    let start = board.find(&'S')[0];
-    let end = board.find(&'E')[0];
-
-    // Doing this as a DFS just since it's easy, even though its unnecessary
-    let mut visited: HashSet<Coord> = HashSet::new();
-    let mut frontier: Vec<(Coord, usize)> = vec![(start, 0)];
-    let mut distance_from_start: HashMap<Coord, usize> = HashMap::from([(start, 0usize)]);
-
-    while let Some((pos, distance)) = frontier.pop() {
-        if !visited.insert(pos) {
-            // Stops us from going backwards without having to track direction
-            continue;
-        }
-
-        distance_from_start.insert(pos, distance);
-
-        if pos == end {
-            break;
-        }
-
-        for neighbour in pos.cardinal_neighbours() {
-            if matches!(board.get(&neighbour), Some('#') | None) {
-                continue;
-            }
+/// Parse the racetrack. Returns the board, and the position of the end.
+pub fn parse_input(input: &str) -> (Board<char>, Coord) {
+    let (board, markers) = Board::parse_with_markers(input, &['S', 'E'], '.').unwrap();
 
-            frontier.push((neighbour, distance + 1));
-        }
-    }
-
-    // Convert distance from start to distance from end
-    let track_length = *distance_from_start.get(&end).unwrap();
+    (board, markers[&'E'])
+}
 
-    distance_from_start
-        .into_iter()
-        .map(|(pos, distance)| (pos, track_length - distance))
+/// Explore the track. Returns a map from position -> how far away the
+/// end is.
+pub fn explore_track(board: &Board<char>, end: Coord) -> HashMap<Coord, usize> {
+    board
+        .distance_map([end], |c| *c != '#')
+        .iter()
+        .filter_map(|(pos, distance)| distance.map(|d| (pos, d as usize)))
         .collect()
 }
 
@@ -74,9 +50,9 @@ pub fn cheat(distances: HashMap<Coord, usize>) -> HashMap<usize, usize> {
 }
 
 pub fn solution(input: &str) -> usize {
-    let board = Board::from_str(input);
+    let (board, end) = parse_input(input);
 
-    let distances = explore_track(&board);
+    let distances = explore_track(&board, end);
 
     let times_saved = cheat(distances);
 
@@ -1,5 +1,5 @@
-use crate::part_1::explore_track;
-use aoc::grid_2d::{Board, Coord};
+use crate::part_1::{explore_track, parse_input};
+use aoc::grid_2d::Coord;
 use std::collections::HashMap;
 
 /// Do some cheating, even more this time. Now we can cheat
@@ -13,27 +13,15 @@ pub fn cheat_harder(distances: HashMap<Coord, usize>) -> HashMap<usize, usize> {
     let mut times_saved: HashMap<usize, usize> = HashMap::new();
 
     for (starting_pos, distance_to_end) in distances.iter() {
-        let Coord(start_row, start_col) = starting_pos;
-
-        // Iterate over a box that's 41 long on each size, and filter
-        // those down by manhattan distance
-        for row in (start_row - 20)..=(start_row + 20) {
-            for col in (start_col - 20)..=(start_col + 20) {
-                let new_pos = Coord(row, col);
-                let distance_cheated = new_pos.manhattan_distance(starting_pos) as usize;
-
-                if distance_cheated > 20 {
-                    continue;
-                }
-
-                if let Some(new_distance) = distances.get(&new_pos) {
-                    let new_distance = new_distance + distance_cheated;
-
-                    if new_distance < *distance_to_end {
-                        // We saved time
-                        let time_saved = distance_to_end - new_distance;
-                        *times_saved.entry(time_saved).or_default() += 1;
-                    }
+        // Every position within 20 steps, in a diamond around the start
+        for (new_pos, distance_cheated) in starting_pos.within_manhattan(20) {
+            if let Some(new_distance) = distances.get(&new_pos) {
+                let new_distance = new_distance + distance_cheated as usize;
+
+                if new_distance < *distance_to_end {
+                    // We saved time
+                    let time_saved = distance_to_end - new_distance;
+                    *times_saved.entry(time_saved).or_default() += 1;
                 }
             }
         }
@@ -43,9 +31,9 @@ pub fn cheat_harder(distances: HashMap<Coord, usize>) -> HashMap<usize, usize> {
 }
 
 pub fn solution(input: &str) -> usize {
-    let board = Board::from_str(input);
+    let (board, end) = parse_input(input);
 
-    let distances = explore_track(&board);
+    let distances = explore_track(&board, end);
 
     let times_saved = cheat_harder(distances);
 
@@ -1,5 +1,6 @@
 use aoc::grid_2d::{Board, Coord, Dir};
-use std::collections::{HashMap, HashSet, VecDeque};
+use aoc::search::bfs_all;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
@@ -66,73 +67,32 @@ pub const DPAD: [[Key; 3]; 2] = [
     [Key::Left, Key::Down, Key::Right],
 ];
 
-pub struct Visit {
-    position: Coord,
-    path: Vec<Key>,
-    visited: HashSet<Coord>,
-}
-
 /// Find all shortest paths between two keys
 pub fn find_shortest_key_paths(board: &Board<Key>, source: Coord, target: Coord) -> Vec<Vec<Key>> {
-    let mut paths: Vec<Vec<Key>> = Vec::new();
-
-    // BFS to get the shortest path from source to target
-    let mut to_visit: VecDeque<Visit> = VecDeque::from([Visit {
-        position: source,
-        path: Vec::new(),
-        visited: HashSet::new(),
-    }]);
-
-    let mut shortest_path = usize::MAX;
-
-    while let Some(Visit {
-        position,
-        mut path,
-        mut visited,
-    }) = to_visit.pop_front()
-    {
-        if position == target && path.len() <= shortest_path {
-            shortest_path = path.len();
-
-            // End with an activate, since we always want to press that key
-            path.push(Key::Activate);
-
-            // Normally we would need to clear paths if we found a longer
-            // one previously, but with BFS / unweighted Dijkstra that isn't
-            // going to happen.
-            paths.push(path);
-
-            continue;
-        }
-
-        if !visited.insert(position) {
-            // Already been here
-            continue;
-        }
-
-        let Some(elem) = board.get(&position) else {
-            // Off the board
-            continue;
-        };
-
-        if matches!(elem, Key::Empty) {
-            // Can't move here
-            continue;
-        }
-
-        for dir in Dir::cardinal() {
-            let neighbour = position + dir;
-            let mut path = path.clone();
-            path.push(dir.into());
-            to_visit.push_back(Visit {
-                position: neighbour,
-                path,
-                visited: visited.clone(),
-            })
-        }
-    }
-
-    paths
+    let Some(shortest_paths) = bfs_all(
+        source,
+        |&position| {
+            position
+                .cardinal_neighbours()
+                .into_iter()
+                // Can't move off the board or onto the gap
+                .filter(move |n| !matches!(board.get(n), None | Some(Key::Empty)))
+        },
+        |position| *position == target,
+    ) else {
+        return Vec::new();
+    };
+
+    shortest_paths
+        .paths()
+        .map(|path| {
+            path.windows(2)
+                .map(|step| Dir::from(step[1] - step[0]).into())
+                // End with an activate, since we always want to press that key
+                .chain(std::iter::once(Key::Activate))
+                .collect()
+        })
+        .collect()
 }
 
 pub type Paths = HashMap<(Key, Key), Vec<Vec<Key>>>;
@@ -143,13 +103,13 @@ pub fn find_all_shortest_paths(board: &Board<Key>) -> Paths {
     let mut paths: Paths = HashMap::new();
 
     for source in board.positions() {
-        let source_element = board.get_unchecked(&source);
+        let source_element = *board.get_unchecked(&source);
         if matches!(source_element, Key::Empty) {
             continue;
         }
 
         for target in board.positions() {
-            let target_element = board.get_unchecked(&target);
+            let target_element = *board.get_unchecked(&target);
             if matches!(target_element, Key::Empty) {
                 continue;
             }
@@ -1,8 +1,8 @@
+use aoc::visualize::svg::{Graph, Node, Shape};
+use aoc::visualize::Color;
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::fmt::Formatter;
-use std::fs::File;
-use std::io::Write;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Gate {
@@ -121,41 +121,43 @@ impl Network {
     }
 
     #[allow(dead_code)]
-    /// Write the network as a mermaid graph to a file
-    pub fn write_mermaid(&self, file: &str) -> std::io::Result<()> {
-        let mut file = File::create(file)?;
-
-        writeln!(file, "graph LR;")?;
+    /// Write the network as an SVG graph to a file
+    pub fn write_svg(&self, file: &str) -> std::io::Result<()> {
+        let mut graph = Graph::new();
 
         let input_wires = self
             .values
             .keys()
-            .map(|key| key.to_string())
-            .sorted()
-            .collect_vec();
+            .filter(|wire| wire.starts_with("x") || wire.starts_with("y"))
+            .sorted();
 
-        // Print each input node
+        // Add each input node
         for wire in input_wires {
-            if wire.starts_with("x") || wire.starts_with("y") {
-                // Inputs get a rounded rectangle
-                writeln!(file, "{}([{}]);", wire, wire)?;
-            }
+            // Inputs get a rounded rectangle
+            graph.add_node(Node::new(wire).shape(Shape::Rounded).fill(Color::Cyan));
         }
 
-        for (output, (lhs, gate, rhs)) in self.dependencies.iter() {
+        for (output, (lhs, gate, rhs)) in self
+            .dependencies
+            .iter()
+            .sorted_by_key(|(output, _)| *output)
+        {
+            let label = format!("{} {} {} -> {}", lhs, gate, rhs, output);
+            let node = Node::new(output).label(&label);
+
             if output.starts_with("z") {
                 // Make final outputs look distinct
-                writeln!(file, "{}[[{} {} {} -> {}]]", output, lhs, gate, rhs, output)?;
+                graph.add_node(node.fill(Color::Yellow));
             } else {
-                writeln!(file, "{}({} {} {} -> {})", output, lhs, gate, rhs, output)?;
+                graph.add_node(node);
             }
 
             // Edges pointing in to the gate
-            writeln!(file, "{} --> {}", lhs, output)?;
-            writeln!(file, "{} --> {}", rhs, output)?;
+            graph.add_edge(lhs, output);
+            graph.add_edge(rhs, output);
         }
 
-        Ok(())
+        graph.save(file)
     }
 
     pub fn bits_to_val(&self, prefix: &str) -> u64 {
@@ -70,7 +70,7 @@ pub fn validate_half_adder(network: &Network, a: &str, b: &str) -> Option<(Strin
 /// through the adder network and validate that each stage is structured properly.
 ///
 /// When an invalid stage is detected, we break and print the gate that failed.
-/// By inspecting the stage in the printed mermaid diagram, we can pretty easily
+/// By inspecting the stage in the SVG diagram of the network, we can pretty easily
 /// see which gates need to be swapped. Those gates are added to `to_swap`, repeating
 /// manually until the full network is valid.
 pub fn solution(input: &str) -> String {
@@ -88,8 +88,8 @@ pub fn solution(input: &str) -> String {
     }
 
     network
-        .write_mermaid("diagram.mermaid")
-        .expect("Failed to write mermaid file");
+        .write_svg("diagram.svg")
+        .expect("Failed to write SVG file");
 
     // Traverse the network, validating that it makes a proper adder
     let (_sum, mut carry) =
@@ -1,4 +1,4 @@
-use aoc::grid_2d::Board;
+use aoc::grid_2d::{Board, Dir};
 
 #[derive(Debug, Eq, PartialEq)]
 enum PatternType {
@@ -20,20 +20,20 @@ impl KeyOrLock {
         let board = Board::from_str(raw);
         let (rows, cols) = board.size();
 
-        let is_lock = board.matrix[0].iter().all(|x| x == &'#');
+        let is_lock = board.row(0).all(|(_, x)| x == &'#');
 
         let heights: Vec<usize> = (0..cols)
             .map(|col| {
+                // Count the #s after the full row, up to the first .
                 if is_lock {
-                    (1..rows)
-                        .find(|&row| board.matrix[row][col] == '.')
-                        .map(|row| row - 1)
+                    board
+                        .ray((1, col).into(), Dir::South)
+                        .position(|(_, x)| x == &'.')
                         .unwrap()
                 } else {
-                    (0..rows - 1)
-                        .rev()
-                        .find(|&row| board.matrix[row][col] == '.')
-                        .map(|row| rows - row - 2)
+                    board
+                        .ray((rows - 2, col).into(), Dir::North)
+                        .position(|(_, x)| x == &'.')
                         .unwrap()
                 }
             })